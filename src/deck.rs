@@ -0,0 +1,359 @@
+use core::fmt;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng, rng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::hand::Hand;
+use crate::hand::card::{PlayingCard, Suit, Value};
+use crate::hand::card_set::CardSet;
+use crate::hand::error::CardError;
+
+// CONSTANTS
+// Black joker, then the red one.
+const JOKER_SUITS: [Suit; 2] = [Suit::Spade, Suit::Heart];
+
+///Deck of cards to deal from.
+///Cards are stored top card last so dealing and burning are a cheap pop.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Deck {
+    cards: Vec<PlayingCard>,
+    burned: Vec<PlayingCard>,
+}
+
+impl Default for Deck {
+    fn default() -> Deck {
+        Deck::new()
+    }
+}
+
+impl Deck {
+    // ---------------------------
+    // Constructors
+    // ---------------------------
+
+    //Unshuffled 52 card deck, ordered by suit then value.
+    pub fn new() -> Deck {
+        Deck::ordered(&[])
+    }
+
+    //Unshuffled 52 card deck with up to two jokers (the black and the red one)
+    //on the end. There are only two, so asking for more is an error.
+    pub fn with_jokers(jokers: usize) -> Result<Deck, CardError> {
+        if jokers > JOKER_SUITS.len() {
+            return Err(CardError::JokerCount {
+                count: jokers,
+                max: JOKER_SUITS.len(),
+            });
+        }
+        Ok(Deck::ordered(&JOKER_SUITS[..jokers]))
+    }
+
+    //Shuffled with the thread RNG. Can't be replayed, use seeded() for that.
+    pub fn shuffled() -> Deck {
+        Deck::shuffled_with(&mut rng())
+    }
+
+    //Same seed, same deal order. ChaCha8 is a fixed algorithm, unlike StdRng
+    //which can change between rand versions, so a seed replays the same deal
+    //on any build.
+    pub fn seeded(seed: u64) -> Deck {
+        Deck::shuffled_with(&mut ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    //Seeded deck with jokers added (see with_jokers).
+    pub fn seeded_with_jokers(seed: u64, jokers: usize) -> Result<Deck, CardError> {
+        let mut deck = Deck::with_jokers(jokers)?;
+        deck.shuffle(&mut ChaCha8Rng::seed_from_u64(seed));
+        Ok(deck)
+    }
+
+    //Shuffled with an injected RNG.
+    pub fn shuffled_with<R: Rng + ?Sized>(rng: &mut R) -> Deck {
+        let mut deck = Deck::new();
+        deck.shuffle(rng);
+        deck
+    }
+
+    //Rigged deck for tests and replays. The first card given is the first card dealt.
+    pub fn from_order(order: Vec<PlayingCard>) -> Deck {
+        let mut cards = order;
+        cards.reverse();
+        Deck {
+            cards,
+            burned: Vec::new(),
+        }
+    }
+
+    //Rigged deck for tests: the cards given are dealt first, eg. "As Kd Qh", then
+    //the rest of a full deck so card conservation still holds.
+    #[cfg(test)]
+    pub fn rigged(top: &str) -> Deck {
+        let mut order: Vec<PlayingCard> =
+            top.split_whitespace().map(|c| c.parse().unwrap()).collect();
+        for card in Deck::new().get_cards() {
+            if !order.contains(&card) {
+                order.push(card);
+            }
+        }
+        Deck::from_order(order)
+    }
+
+    // Every card of every suit, then a joker for each of the given suits.
+    fn ordered(joker_suits: &[Suit]) -> Deck {
+        let mut cards = Vec::new();
+        for &suit in &Suit::ALL {
+            for &value in &Value::ALL {
+                cards.push(PlayingCard::new(suit, value));
+            }
+        }
+        for &suit in joker_suits {
+            cards.push(PlayingCard::joker(suit));
+        }
+        Deck {
+            cards,
+            burned: Vec::new(),
+        }
+    }
+
+    // -------------------------
+    // Card Operations
+    // -------------------------
+
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+    }
+
+    //Takes the top card off the deck.
+    pub fn deal(&mut self) -> Result<PlayingCard, CardError> {
+        self.cards.pop().ok_or(CardError::DeckEmpty)
+    }
+
+    //Moves the top card to the burn pile and returns it.
+    pub fn burn(&mut self) -> Result<PlayingCard, CardError> {
+        let card = self.deal()?;
+        self.burned.push(card);
+        Ok(card)
+    }
+
+    //Deals the top card into a hand. Nothing leaves the deck if the hand won't take it.
+    pub fn deal_to(&mut self, hand: &mut Hand) -> Result<PlayingCard, CardError> {
+        if hand.is_full() {
+            return Err(CardError::HandFull);
+        }
+        let card = self.peek().ok_or(CardError::DeckEmpty)?;
+        hand.draw(card)?;
+        self.cards.pop();
+        Ok(card)
+    }
+
+    //Looks at the top card without dealing it.
+    pub fn peek(&self) -> Option<PlayingCard> {
+        self.cards.last().copied()
+    }
+
+    //Number of cards left to deal.
+    pub fn remaining(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    //Cards left in the deck, in dealing order.
+    pub fn get_cards(&self) -> Vec<PlayingCard> {
+        self.cards.iter().rev().copied().collect()
+    }
+
+    //First joker left in the deck, if it has any.
+    pub fn find_joker(&self) -> Option<PlayingCard> {
+        self.cards.iter().rev().find(|c| c.is_joker()).copied()
+    }
+
+    //Just a getter.
+    pub fn get_burned(&self) -> &Vec<PlayingCard> {
+        &self.burned
+    }
+
+    // -------------------------
+    // Validation
+    // -------------------------
+
+    //Checks every one of the 52 cards sits in exactly one place: this deck, its
+    //burn pile, one of the hands, or the muck (discarded cards). Jokers are only
+    //checked for duplicates, a deck doesn't have to have them.
+    //Rigged decks built with from_order will report whatever they left out.
+    pub fn check_conservation(
+        &self,
+        hands: &[&Hand],
+        muck: &[PlayingCard],
+    ) -> Result<(), CardError> {
+        let mut seen = CardSet::new();
+        let everywhere = self
+            .cards
+            .iter()
+            .chain(self.burned.iter())
+            .chain(hands.iter().flat_map(|h| h.get_hand().iter()))
+            .chain(muck.iter());
+        for &card in everywhere {
+            if !seen.insert(card) {
+                return Err(CardError::DuplicateCard(card));
+            }
+        }
+        match seen.complement().iter().next() {
+            Some(card) => Err(CardError::MissingCard(card)),
+            None => Ok(()),
+        }
+    }
+
+    //Same check, but only in debug builds and it panics, so it can be dropped in
+    //after every deal or discard to catch corruption where it happens.
+    pub fn debug_check_conservation(&self, hands: &[&Hand], muck: &[PlayingCard]) {
+        if cfg!(debug_assertions)
+            && let Err(err) = self.check_conservation(hands, muck)
+        {
+            panic!("Card conservation broken: {}", err);
+        }
+    }
+}
+
+impl fmt::Display for Deck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for card in self.get_cards() {
+            write!(f, "{} , ", card)?;
+        }
+        Ok(())
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn seeded_test() {
+        let mut deck = Deck::seeded(42);
+        let mut same = Deck::seeded(42);
+        assert_eq!(deck.remaining(), 52);
+        assert_eq!(deck, same);
+        for _ in 0..52 {
+            assert_eq!(deck.deal(), same.deal());
+        }
+        assert_eq!(deck.deal(), Err(CardError::DeckEmpty));
+        assert_ne!(Deck::seeded(1), Deck::seeded(2));
+    }
+
+    #[test]
+    fn from_order_test() {
+        let order: Vec<PlayingCard> = vec!["As", "Kd", "Qh", "2c"]
+            .into_iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let mut deck = Deck::from_order(order.clone());
+        assert_eq!(deck.get_cards(), order);
+        assert_eq!(deck.peek(), Some(order[0]));
+
+        assert_eq!(deck.burn(), Ok(order[0]));
+        let mut hand = Hand::default();
+        assert_eq!(deck.deal_to(&mut hand), Ok(order[1]));
+        assert_eq!(deck.deal_to(&mut hand), Ok(order[2]));
+        assert_eq!(deck.get_burned(), &vec![order[0]]);
+        assert_eq!(hand.get_hand(), &vec![order[1], order[2]]);
+        assert_eq!(deck.remaining(), 1);
+
+        assert_eq!(deck.deal(), Ok(order[3]));
+        assert_eq!(deck.deal_to(&mut hand), Err(CardError::DeckEmpty));
+        assert_eq!(deck.burn(), Err(CardError::DeckEmpty));
+    }
+
+    #[test]
+    fn deal_to_full_hand_test() {
+        let mut deck = Deck::new();
+        let mut hand = Hand::default();
+        for _ in 0..5 {
+            deck.deal_to(&mut hand).unwrap();
+        }
+        assert_eq!(deck.deal_to(&mut hand), Err(CardError::HandFull));
+        // Card stays on the deck when the hand can't take it.
+        assert_eq!(deck.remaining(), 47);
+
+        let ace = PlayingCard::new(Suit::Club, Value::Ace);
+        let mut rigged = Deck::from_order(vec![ace]);
+        let mut holding_ace = Hand::default();
+        holding_ace.draw(ace).unwrap();
+        assert_eq!(
+            rigged.deal_to(&mut holding_ace),
+            Err(CardError::DuplicateCard(ace))
+        );
+        assert_eq!(rigged.remaining(), 1);
+    }
+
+    #[test]
+    fn conservation_test() {
+        let mut deck = Deck::seeded(7);
+        let mut player = Hand::default();
+        let mut house = Hand::default();
+        let mut muck = Vec::new();
+        assert_eq!(deck.check_conservation(&[], &muck), Ok(()));
+
+        for _ in 0..5 {
+            deck.deal_to(&mut player).unwrap();
+            deck.deal_to(&mut house).unwrap();
+        }
+        deck.burn().unwrap();
+        assert_eq!(deck.check_conservation(&[&player, &house], &muck), Ok(()));
+
+        // Discarded cards have to be tracked in the muck.
+        let discarded = player.group_discard(vec![0, 1]).unwrap();
+        assert!(matches!(
+            deck.check_conservation(&[&player, &house], &muck),
+            Err(CardError::MissingCard(_))
+        ));
+        muck.extend(discarded);
+        deck.debug_check_conservation(&[&player, &house], &muck);
+
+        // Same card in two hands.
+        let copied = house.get_hand()[0];
+        player.draw(copied).unwrap();
+        assert_eq!(
+            deck.check_conservation(&[&player, &house], &muck),
+            Err(CardError::DuplicateCard(copied))
+        );
+    }
+
+    #[test]
+    fn jokers_test() {
+        let deck = Deck::with_jokers(2).unwrap();
+        assert_eq!(deck.remaining(), 54);
+        let jokers: Vec<PlayingCard> = deck
+            .get_cards()
+            .into_iter()
+            .filter(|c| c.is_joker())
+            .collect();
+        assert_eq!(
+            jokers,
+            vec![
+                PlayingCard::joker(Suit::Heart),
+                PlayingCard::joker(Suit::Spade)
+            ]
+        );
+        assert_eq!(
+            Deck::with_jokers(5),
+            Err(CardError::JokerCount { count: 5, max: 2 })
+        );
+        assert_eq!(
+            Deck::seeded_with_jokers(3, 3).map(|d| d.remaining()),
+            Err(CardError::JokerCount { count: 3, max: 2 })
+        );
+        assert_eq!(Deck::with_jokers(0), Ok(Deck::new()));
+
+        let mut seeded = Deck::seeded_with_jokers(3, 1).unwrap();
+        assert_eq!(Ok(seeded.clone()), Deck::seeded_with_jokers(3, 1));
+        assert_eq!(seeded.remaining(), 53);
+        assert_eq!(seeded.check_conservation(&[], &[]), Ok(()));
+        let mut hand = Hand::new(53);
+        while seeded.deal_to(&mut hand).is_ok() {}
+        assert_eq!(seeded.check_conservation(&[&hand], &[]), Ok(()));
+    }
+}
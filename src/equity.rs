@@ -0,0 +1,542 @@
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::hand::card::PlayingCard;
+use crate::hand::card_set::CardSet;
+use crate::hand::evaluator::{best_hand, combinations};
+use crate::hand::rank::HandRank;
+
+pub mod error;
+pub mod range;
+use error::EquityError;
+use range::Range;
+
+// CONSTANTS
+const BOARD_SIZE: usize = 5;
+const MAX_HOLE_CARDS: usize = 2;
+const MIN_HANDS: usize = 2;
+// Tries at dealing every range a combo without any two sharing a card, before
+// giving up on a sample.
+const DEAL_ATTEMPTS: usize = 1_000;
+//Matchups times boards to enumerate before falling back to sampling. A hand
+//against a hand is exhaustive from the flop on, heads-up preflop (1.7 million
+//boards) is sampled.
+pub const DEFAULT_EXHAUSTIVE_LIMIT: u64 = 100_000;
+pub const DEFAULT_SAMPLES: u64 = 20_000;
+
+///How one hand or range did over every board that was run out. Counts are
+///weighted by the range weights, so they're only whole numbers for plain hands.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HandEquity {
+    wins: f64,
+    ties: f64,
+    losses: f64,
+    share: f64,
+}
+
+impl HandEquity {
+    //Standard getter.
+    pub fn get_wins(&self) -> f64 {
+        self.wins
+    }
+    //Standard getter.
+    pub fn get_ties(&self) -> f64 {
+        self.ties
+    }
+    //Standard getter.
+    pub fn get_losses(&self) -> f64 {
+        self.losses
+    }
+
+    //Boards this hand was scored on (weighted).
+    pub fn total(&self) -> f64 {
+        self.wins + self.ties + self.losses
+    }
+
+    pub fn win_percent(&self) -> f64 {
+        percent(self.wins, self.total())
+    }
+
+    pub fn tie_percent(&self) -> f64 {
+        percent(self.ties, self.total())
+    }
+
+    pub fn loss_percent(&self) -> f64 {
+        percent(self.losses, self.total())
+    }
+
+    //Average share of the pot as a percentage, a tie counting as an even split
+    //between everyone in it.
+    pub fn equity(&self) -> f64 {
+        percent(self.share, self.total())
+    }
+}
+
+///Result of an equity calculation, one HandEquity per hand in the order given.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equity {
+    hands: Vec<HandEquity>,
+    boards: u64,
+    exhaustive: bool,
+}
+
+impl Equity {
+    //Just a getter.
+    pub fn get_hands(&self) -> &Vec<HandEquity> {
+        &self.hands
+    }
+    //Number of boards run out, over every matchup.
+    pub fn get_boards(&self) -> u64 {
+        self.boards
+    }
+    //True when every possible board was checked, false when they were sampled.
+    pub fn is_exhaustive(&self) -> bool {
+        self.exhaustive
+    }
+}
+
+///Win/tie/loss odds for any number of known Hold'em hands or ranges, with an
+///optional partial board and dead cards (eg. folded or burned cards someone saw).
+///Every matchup and board is checked when there are few enough of them,
+///otherwise hands and boards are sampled with a seeded RNG so the same seed
+///gives the same answer.
+#[derive(Debug, Clone)]
+pub struct EquityCalculator {
+    ranges: Vec<Range>,
+    board: Vec<PlayingCard>,
+    dead: Vec<PlayingCard>,
+    exhaustive_limit: u64,
+    samples: u64,
+    seed: u64,
+}
+
+impl EquityCalculator {
+    //Known hands of one or two hole cards each. No board or dead cards until set.
+    pub fn new(hands: Vec<Vec<PlayingCard>>) -> EquityCalculator {
+        EquityCalculator::with_ranges(hands.into_iter().map(Range::from_hand).collect())
+    }
+
+    //Ranges instead of known hands. A range of one combo is a known hand, so
+    //hand against range is just a mix of the two.
+    pub fn with_ranges(ranges: Vec<Range>) -> EquityCalculator {
+        EquityCalculator {
+            ranges,
+            board: Vec::new(),
+            dead: Vec::new(),
+            exhaustive_limit: DEFAULT_EXHAUSTIVE_LIMIT,
+            samples: DEFAULT_SAMPLES,
+            seed: 0,
+        }
+    }
+
+    // -------------------------
+    // Setters
+    // -------------------------
+
+    //Board cards already out, 0-5 of them.
+    pub fn set_board(&mut self, board: Vec<PlayingCard>) {
+        self.board = board;
+    }
+
+    //Cards known to be out of play, they can't come on the board.
+    pub fn set_dead(&mut self, dead: Vec<PlayingCard>) {
+        self.dead = dead;
+    }
+
+    //Most matchups times boards that will be enumerated. Anything more gets sampled.
+    pub fn set_exhaustive_limit(&mut self, limit: u64) {
+        self.exhaustive_limit = limit;
+    }
+
+    //How many boards to sample, and the seed to sample them with.
+    pub fn set_samples(&mut self, samples: u64, seed: u64) {
+        self.samples = samples;
+        self.seed = seed;
+    }
+
+    // -------------------------
+    // Calculation
+    // -------------------------
+
+    //Number of different ways the board can be finished for one matchup.
+    pub fn possible_boards(&self) -> Result<u64, EquityError> {
+        let (ranges, known) = self.live_ranges()?;
+        let hole_cards: usize = ranges
+            .iter()
+            .map(|r| r.get_combos()[0].get_cards().len())
+            .sum();
+        let left = CardSet::full_deck().count() - known.count() - hole_cards;
+        Ok(choose(left, BOARD_SIZE - self.board.len()))
+    }
+
+    //Runs the boards out for every matchup and scores every hand on each.
+    pub fn calculate(&self) -> Result<Equity, EquityError> {
+        let (ranges, known) = self.live_ranges()?;
+        let matchups: u64 = ranges.iter().map(|r| r.len() as u64).product();
+        let work = matchups.saturating_mul(self.possible_boards()?);
+        let mut tally = vec![HandEquity::default(); ranges.len()];
+
+        let exhaustive = work <= self.exhaustive_limit;
+        let boards = if exhaustive {
+            let mut boards = 0;
+            self.enumerate(
+                &ranges,
+                known,
+                &mut Vec::new(),
+                1.0,
+                &mut tally,
+                &mut boards,
+            );
+            // Every matchup shared a card, same as sampling failing to deal one.
+            if boards == 0 {
+                return Err(EquityError::NoMatchup);
+            }
+            boards
+        } else {
+            self.sample(&ranges, known, &mut tally)?;
+            self.samples
+        };
+        Ok(Equity {
+            hands: tally,
+            boards,
+            exhaustive,
+        })
+    }
+
+    // Checks the ranges, board and dead cards. Returns the ranges with every
+    // combo that uses a known card dropped, and the known cards. Ranges of one
+    // combo are known hands, so they count as known cards for everyone else.
+    fn live_ranges(&self) -> Result<(Vec<Range>, CardSet), EquityError> {
+        if self.ranges.len() < MIN_HANDS {
+            return Err(EquityError::NotEnoughHands(self.ranges.len()));
+        }
+        for (hand, range) in self.ranges.iter().enumerate() {
+            for combo in range.get_combos() {
+                let count = combo.get_cards().len();
+                if count == 0 || count > MAX_HOLE_CARDS {
+                    return Err(EquityError::HoleCardCount { hand, count });
+                }
+                // Every combo, not just known hands, or a joker gets as far as scoring.
+                if let Some(&joker) = combo.get_cards().iter().find(|c| c.is_joker()) {
+                    return Err(EquityError::WildCard(joker));
+                }
+            }
+        }
+        if self.board.len() > BOARD_SIZE {
+            return Err(EquityError::BoardSize(self.board.len()));
+        }
+        let mut known = CardSet::new();
+        let fixed_hands = self
+            .ranges
+            .iter()
+            .filter(|r| r.len() == 1)
+            .flat_map(|r| r.get_combos()[0].get_cards().iter());
+        let everything = self.board.iter().chain(self.dead.iter()).chain(fixed_hands);
+        for &card in everything {
+            if card.is_joker() {
+                return Err(EquityError::WildCard(card));
+            }
+            if !known.insert(card) {
+                return Err(EquityError::DuplicateCard(card));
+            }
+        }
+        let needed = BOARD_SIZE - self.board.len();
+        let left = CardSet::full_deck().count() - known.count();
+        if left < needed {
+            return Err(EquityError::NotEnoughCards { needed, left });
+        }
+
+        let mut ranges = Vec::new();
+        for (hand, range) in self.ranges.iter().enumerate() {
+            let live = if range.len() == 1 {
+                range.clone()
+            } else {
+                range.without_blocked(known)
+            };
+            if live.is_empty() || live.total_weight() <= 0.0 {
+                return Err(EquityError::EmptyRange(hand));
+            }
+            ranges.push(live);
+        }
+        let board_known = CardSet::from_iter(self.board.iter().chain(self.dead.iter()).copied());
+        Ok((ranges, board_known))
+    }
+
+    // Every matchup of one combo per range with no shared cards, then every
+    // board for it. Each board counts for the product of the combo weights.
+    fn enumerate(
+        &self,
+        ranges: &[Range],
+        used: CardSet,
+        holes: &mut Vec<Vec<PlayingCard>>,
+        weight: f64,
+        tally: &mut [HandEquity],
+        boards: &mut u64,
+    ) {
+        let Some(range) = ranges.get(holes.len()) else {
+            let live = used.complement().to_vec();
+            let missing = BOARD_SIZE - self.board.len();
+            for combo in combinations(live.len(), missing) {
+                let runout: Vec<PlayingCard> = combo.iter().map(|&i| live[i]).collect();
+                self.score(holes, &runout, weight, tally);
+                *boards += 1;
+            }
+            return;
+        };
+        for combo in range.get_combos() {
+            if combo.is_blocked_by(used) || combo.get_weight() <= 0.0 {
+                continue;
+            }
+            let cards = CardSet::from_iter(combo.get_cards().iter().copied());
+            holes.push(combo.get_cards().clone());
+            self.enumerate(
+                ranges,
+                used | cards,
+                holes,
+                weight * combo.get_weight(),
+                tally,
+                boards,
+            );
+            holes.pop();
+        }
+    }
+
+    // Deals every range a combo, picked by weight, then a random board. Deals
+    // where two ranges share a card are thrown away and redealt.
+    fn sample(
+        &self,
+        ranges: &[Range],
+        known: CardSet,
+        tally: &mut [HandEquity],
+    ) -> Result<(), EquityError> {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        let cumulative: Vec<Vec<f64>> = ranges
+            .iter()
+            .map(|range| {
+                range
+                    .get_combos()
+                    .iter()
+                    .scan(0.0, |total, combo| {
+                        *total += combo.get_weight();
+                        Some(*total)
+                    })
+                    .collect()
+            })
+            .collect();
+        let missing = BOARD_SIZE - self.board.len();
+        for _ in 0..self.samples {
+            let (holes, used) = deal_combos(ranges, &cumulative, known, &mut rng)?;
+            let mut live = used.complement().to_vec();
+            let (runout, _) = live.partial_shuffle(&mut rng, missing);
+            let runout = runout.to_vec();
+            self.score(&holes, &runout, 1.0, tally);
+        }
+        Ok(())
+    }
+
+    // Ranks every hand on one finished board and adds the result to the tally.
+    fn score(
+        &self,
+        holes: &[Vec<PlayingCard>],
+        runout: &[PlayingCard],
+        weight: f64,
+        tally: &mut [HandEquity],
+    ) {
+        let ranks: Vec<HandRank> = holes
+            .iter()
+            .map(|hole| {
+                let mut cards = hole.clone();
+                cards.extend(self.board.iter().chain(runout.iter()));
+                best_hand(&cards)
+                    .expect("Hole cards plus a full board make 6 or 7 cards.")
+                    .rank()
+            })
+            .collect();
+        let best = *ranks.iter().max().expect("There are at least two hands.");
+        let winners = ranks.iter().filter(|&&r| r == best).count();
+        for (result, rank) in tally.iter_mut().zip(ranks) {
+            if rank != best {
+                result.losses += weight;
+            } else if winners == 1 {
+                result.wins += weight;
+                result.share += weight;
+            } else {
+                result.ties += weight;
+                result.share += weight / winners as f64;
+            }
+        }
+    }
+}
+
+// One combo per range, picked by weight, none of them sharing a card.
+fn deal_combos<R: Rng>(
+    ranges: &[Range],
+    cumulative: &[Vec<f64>],
+    known: CardSet,
+    rng: &mut R,
+) -> Result<(Vec<Vec<PlayingCard>>, CardSet), EquityError> {
+    'attempt: for _ in 0..DEAL_ATTEMPTS {
+        let mut used = known;
+        let mut holes = Vec::new();
+        for (range, totals) in ranges.iter().zip(cumulative) {
+            let total = *totals.last().expect("Live ranges aren't empty.");
+            let pick = rng.random::<f64>() * total;
+            let index = totals.partition_point(|&t| t <= pick).min(totals.len() - 1);
+            let combo = &range.get_combos()[index];
+            if combo.is_blocked_by(used) {
+                continue 'attempt;
+            }
+            used = used | CardSet::from_iter(combo.get_cards().iter().copied());
+            holes.push(combo.get_cards().clone());
+        }
+        return Ok((holes, used));
+    }
+    Err(EquityError::NoMatchup)
+}
+
+// Ways of picking k things out of n.
+fn choose(n: usize, k: usize) -> u64 {
+    if k > n {
+        return 0;
+    }
+    (0..k as u64).fold(1, |ways, i| ways * (n as u64 - i) / (i + 1))
+}
+
+fn percent(part: f64, total: f64) -> f64 {
+    if total <= 0.0 {
+        return 0.0;
+    }
+    100.0 * part / total
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hand::card::Suit;
+    use range::Combo;
+
+    fn cards(s: &str) -> Vec<PlayingCard> {
+        s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn exhaustive_test() {
+        // Nut flush draw against a set on the turn. 9 spades left, but the 3
+        // fills the set up and the queen makes quads.
+        let mut calc = EquityCalculator::new(vec![cards("As Ks"), cards("Qd Qc")]);
+        calc.set_board(cards("2s 7s Qh 3c"));
+        assert_eq!(calc.possible_boards(), Ok(44));
+        let equity = calc.calculate().unwrap();
+        assert!(equity.is_exhaustive());
+        assert_eq!(equity.get_boards(), 44);
+        let flush_draw = equity.get_hands()[0];
+        assert_eq!(flush_draw.get_wins(), 7.0);
+        assert_eq!(flush_draw.get_losses(), 37.0);
+        assert_eq!(equity.get_hands()[1].get_wins(), 37.0);
+
+        // Killing two of the outs.
+        calc.set_dead(cards("4s 5s"));
+        let equity = calc.calculate().unwrap();
+        assert_eq!(equity.get_boards(), 42);
+        assert_eq!(equity.get_hands()[0].get_wins(), 5.0);
+    }
+
+    #[test]
+    fn tie_test() {
+        // Royal flush on the board, everyone plays it.
+        let mut calc = EquityCalculator::new(vec![cards("2c 3d"), cards("4h 5c"), cards("7d 8h")]);
+        calc.set_board(cards("As Ks Qs Js Ts"));
+        let equity = calc.calculate().unwrap();
+        assert_eq!(equity.get_boards(), 1);
+        for hand in equity.get_hands() {
+            assert_eq!(hand.tie_percent(), 100.0);
+            assert!((hand.equity() - 100.0 / 3.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn monte_carlo_test() {
+        // Aces against kings all in preflop is about 82-18.
+        let mut calc = EquityCalculator::new(vec![cards("Ah Ad"), cards("Kh Kd")]);
+        calc.set_samples(3_000, 7);
+        let equity = calc.calculate().unwrap();
+        assert!(!equity.is_exhaustive());
+        assert_eq!(equity.get_boards(), 3_000);
+        assert!((equity.get_hands()[0].equity() - 82.0).abs() < 3.0);
+        let total: f64 = equity.get_hands().iter().map(|h| h.equity()).sum();
+        assert!((total - 100.0).abs() < 1e-9);
+        // Same seed, same answer.
+        assert_eq!(calc.calculate().unwrap(), equity);
+    }
+
+    #[test]
+    fn errors_test() {
+        let calc = EquityCalculator::new(vec![cards("As Ks")]);
+        assert_eq!(calc.calculate(), Err(EquityError::NotEnoughHands(1)));
+        let calc = EquityCalculator::new(vec![cards("As Ks Qs"), cards("2c 2d")]);
+        assert_eq!(
+            calc.calculate(),
+            Err(EquityError::HoleCardCount { hand: 0, count: 3 })
+        );
+        let mut calc = EquityCalculator::new(vec![cards("As Ks"), cards("2c 2d")]);
+        calc.set_board(cards("As 7h 8h"));
+        assert_eq!(
+            calc.calculate(),
+            Err(EquityError::DuplicateCard("As".parse().unwrap()))
+        );
+
+        // A joker hiding in a range of more than one combo.
+        let joker = PlayingCard::joker(Suit::Spade);
+        let mut range = Range::new();
+        range.add(Combo::new(cards("Qd Qc"), 1.0));
+        range.add(Combo::new(vec![joker, "Kd".parse().unwrap()], 1.0));
+        let calc = EquityCalculator::with_ranges(vec![Range::from_hand(cards("As Ks")), range]);
+        assert_eq!(calc.calculate(), Err(EquityError::WildCard(joker)));
+    }
+
+    #[test]
+    fn range_test() {
+        // Top pair against a range of underpairs on the river wins every matchup.
+        let hand = Range::from_hand(cards("As Ks"));
+        let mut calc = EquityCalculator::with_ranges(vec![hand, "QQ, JJ".parse().unwrap()]);
+        calc.set_board(cards("Ah 7d 2c 3h 9s"));
+        let equity = calc.calculate().unwrap();
+        assert!(equity.is_exhaustive());
+        assert_eq!(equity.get_boards(), 12);
+        assert_eq!(equity.get_hands()[0].win_percent(), 100.0);
+
+        // A combo with no weight never gets dealt.
+        let mut calc = EquityCalculator::with_ranges(vec![
+            Range::from_hand(cards("As Ks")),
+            "QdQc, JdJc:0".parse().unwrap(),
+        ]);
+        calc.set_board(cards("2s 7s Qh 3c"));
+        let equity = calc.calculate().unwrap();
+        assert_eq!(equity.get_boards(), 44);
+        assert_eq!(equity.get_hands()[0].get_wins(), 7.0);
+
+        // Range against range, sampled.
+        let mut calc =
+            EquityCalculator::with_ranges(vec!["AA".parse().unwrap(), "KK".parse().unwrap()]);
+        calc.set_samples(3_000, 11);
+        let equity = calc.calculate().unwrap();
+        assert!(!equity.is_exhaustive());
+        assert!((equity.get_hands()[0].equity() - 82.0).abs() < 3.0);
+
+        // The hand and board leave no aces pair to deal.
+        let mut calc = EquityCalculator::with_ranges(vec![
+            Range::from_hand(cards("As Ah")),
+            "AA".parse().unwrap(),
+        ]);
+        calc.set_board(cards("Ad 7h 2c"));
+        assert_eq!(calc.calculate(), Err(EquityError::EmptyRange(1)));
+
+        // Both ranges are down to the same two aces, so no matchup can be dealt.
+        let mut calc =
+            EquityCalculator::with_ranges(vec!["AA".parse().unwrap(), "AA".parse().unwrap()]);
+        calc.set_board(cards("Ad Ac 7h"));
+        assert_eq!(calc.calculate(), Err(EquityError::NoMatchup));
+    }
+}
@@ -0,0 +1,67 @@
+use core::fmt;
+
+use crate::hand::card::PlayingCard;
+
+///Reasons an equity calculation can't be run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EquityError {
+    NotEnoughHands(usize),
+    HoleCardCount { hand: usize, count: usize },
+    BoardSize(usize),
+    DuplicateCard(PlayingCard),
+    WildCard(PlayingCard),
+    NotEnoughCards { needed: usize, left: usize },
+    EmptyRange(usize),
+    NoMatchup,
+}
+
+impl fmt::Display for EquityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EquityError::NotEnoughHands(count) => {
+                write!(f, "Need at least 2 hands to compare, got {}", count)
+            }
+            EquityError::HoleCardCount { hand, count } => {
+                write!(f, "Hand {} has {} cards, needs 1 or 2", hand + 1, count)
+            }
+            EquityError::BoardSize(size) => {
+                write!(f, "A board has at most 5 cards, got {}", size)
+            }
+            EquityError::DuplicateCard(card) => write!(f, "{} shows up more than once", card),
+            EquityError::WildCard(card) => write!(f, "{} can't be used, no wild cards here", card),
+            EquityError::NotEnoughCards { needed, left } => {
+                write!(
+                    f,
+                    "Need {} cards to finish the board, {} left",
+                    needed, left
+                )
+            }
+            EquityError::EmptyRange(hand) => {
+                write!(f, "Every combo in range {} is blocked", hand + 1)
+            }
+            EquityError::NoMatchup => write!(f, "The ranges can't be dealt without sharing cards"),
+        }
+    }
+}
+
+impl std::error::Error for EquityError {}
+
+///Reasons a range failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    Empty,
+    InvalidToken(String),
+    InvalidWeight(String),
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RangeError::Empty => write!(f, "Range has no hands in it"),
+            RangeError::InvalidToken(s) => write!(f, "'{}' is not a hand or range", s),
+            RangeError::InvalidWeight(s) => write!(f, "'{}' is not a weight between 0 and 1", s),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
@@ -0,0 +1,344 @@
+use core::fmt;
+use std::str::FromStr;
+
+use super::error::RangeError;
+use crate::hand::card::{PlayingCard, Suit, Value};
+use crate::hand::card_set::CardSet;
+
+// CONSTANTS
+const FULL_WEIGHT: f64 = 1.0;
+
+///One starting hand in a range and how often it's played, 0 to 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Combo {
+    cards: Vec<PlayingCard>,
+    weight: f64,
+}
+
+impl Combo {
+    pub fn new(cards: Vec<PlayingCard>, weight: f64) -> Combo {
+        Combo { cards, weight }
+    }
+    //Standard getter.
+    pub fn get_cards(&self) -> &Vec<PlayingCard> {
+        &self.cards
+    }
+    //Standard getter.
+    pub fn get_weight(&self) -> f64 {
+        self.weight
+    }
+    //True if the combo holds any of the given cards.
+    pub fn is_blocked_by(&self, known: CardSet) -> bool {
+        self.cards.iter().any(|&c| known.contains(c))
+    }
+}
+
+///Set of starting hands someone could hold, eg. "QQ+, AKs, 76s-54s, A5o".
+///Parsing expands the notation into every two card combo it covers. A combo
+///only shows up once, whatever is listed last decides its weight.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Range {
+    combos: Vec<Combo>,
+}
+
+impl Range {
+    //Empty range.
+    pub fn new() -> Range {
+        Range { combos: Vec::new() }
+    }
+
+    //Range of one known hand.
+    pub fn from_hand(cards: Vec<PlayingCard>) -> Range {
+        Range {
+            combos: vec![Combo::new(cards, FULL_WEIGHT)],
+        }
+    }
+
+    //Just a getter.
+    pub fn get_combos(&self) -> &Vec<Combo> {
+        &self.combos
+    }
+
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    //Sum of every combo's weight, ie. how many combos the range is really worth.
+    pub fn total_weight(&self) -> f64 {
+        self.combos.iter().map(|c| c.weight).sum()
+    }
+
+    //Adds a combo, replacing the weight if the same cards are already in.
+    pub fn add(&mut self, combo: Combo) {
+        let cards = CardSet::from_iter(combo.cards.iter().copied());
+        let existing = self
+            .combos
+            .iter()
+            .position(|c| CardSet::from_iter(c.cards.iter().copied()) == cards);
+        match existing {
+            Some(index) => self.combos[index] = combo,
+            None => self.combos.push(combo),
+        }
+    }
+
+    //Same range without the combos that use a known card (the board, dead
+    //cards or someone else's hand).
+    pub fn without_blocked(&self, known: CardSet) -> Range {
+        Range {
+            combos: self
+                .combos
+                .iter()
+                .filter(|c| !c.is_blocked_by(known))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+//Comma separated tokens, each one of:
+//  "AA", "AKs", "AKo", "AK" (suited and offsuit)    a single hand class
+//  "QQ+", "A2s+", "KTo+"                              pairs up to aces, kickers up to one under the top card
+//  "22-55", "A5s-A2s", "76s-54s"                      a span of pairs, kickers, or connectors with the same gap
+//  "AsKs"                                             one exact combo
+//with an optional ":weight" on the end (eg. "AKo:0.5").
+impl FromStr for Range {
+    type Err = RangeError;
+
+    fn from_str(s: &str) -> Result<Range, RangeError> {
+        let mut range = Range::new();
+        for token in s.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            let (body, weight) = match token.split_once(':') {
+                Some((body, weight)) => (body.trim(), parse_weight(weight)?),
+                None => (token, FULL_WEIGHT),
+            };
+            for cards in expand(body)? {
+                range.add(Combo::new(cards, weight));
+            }
+        }
+        if range.is_empty() {
+            return Err(RangeError::Empty);
+        }
+        Ok(range)
+    }
+}
+
+impl fmt::Display for Range {
+    //Written as the combos, eg. "♠A♠K, ♥A♥K" with any weight under one after a colon.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<String> = self
+            .combos
+            .iter()
+            .map(|combo| {
+                let cards: String = combo.cards.iter().map(|c| c.to_string()).collect();
+                if combo.weight < FULL_WEIGHT {
+                    format!("{}:{}", cards, combo.weight)
+                } else {
+                    cards
+                }
+            })
+            .collect();
+        write!(f, "{}", names.join(", "))
+    }
+}
+
+///Pair, suited or offsuit hand class such as "AKs", before it's expanded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct HandClass {
+    high: Value,
+    low: Value,
+    suited: Option<bool>,
+}
+
+impl HandClass {
+    fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    // Same class with both values moved, keeping the suitedness.
+    fn with_values(&self, high: usize, low: usize) -> HandClass {
+        HandClass {
+            high: Value::ALL[high],
+            low: Value::ALL[low],
+            suited: self.suited,
+        }
+    }
+
+    // Every combo the class covers.
+    fn combos(&self) -> Vec<Vec<PlayingCard>> {
+        let mut combos = Vec::new();
+        for (i, &first) in Suit::ALL.iter().enumerate() {
+            for (j, &second) in Suit::ALL.iter().enumerate() {
+                let keep = if self.is_pair() {
+                    i < j
+                } else {
+                    match self.suited {
+                        Some(true) => i == j,
+                        Some(false) => i != j,
+                        None => true,
+                    }
+                };
+                if keep {
+                    combos.push(vec![
+                        PlayingCard::new(first, self.high),
+                        PlayingCard::new(second, self.low),
+                    ]);
+                }
+            }
+        }
+        combos
+    }
+}
+
+impl FromStr for HandClass {
+    type Err = RangeError;
+
+    // Two values then an optional s or o, either value first.
+    fn from_str(s: &str) -> Result<HandClass, RangeError> {
+        let invalid = || RangeError::InvalidToken(s.to_string());
+        let chars: Vec<char> = s.chars().collect();
+        if chars.len() < 2 || chars.len() > 3 {
+            return Err(invalid());
+        }
+        let first: Value = chars[0].to_string().parse().map_err(|_| invalid())?;
+        let second: Value = chars[1].to_string().parse().map_err(|_| invalid())?;
+        if first == Value::Joker || second == Value::Joker {
+            return Err(invalid());
+        }
+        let suited = match chars.get(2) {
+            None => None,
+            Some('s' | 'S') => Some(true),
+            Some('o' | 'O') => Some(false),
+            Some(_) => return Err(invalid()),
+        };
+        if first == second && suited.is_some() {
+            return Err(invalid());
+        }
+        Ok(HandClass {
+            high: first.max(second),
+            low: first.min(second),
+            suited,
+        })
+    }
+}
+
+// Every combo one token (without its weight) stands for.
+fn expand(body: &str) -> Result<Vec<Vec<PlayingCard>>, RangeError> {
+    let invalid = || RangeError::InvalidToken(body.to_string());
+    if let Some(combo) = exact_combo(body) {
+        return Ok(vec![combo]);
+    }
+    let classes: Vec<HandClass> = if let Some(class) = body.strip_suffix('+') {
+        let class: HandClass = class.parse()?;
+        let (high, low) = (class.high.index(), class.low.index());
+        if class.is_pair() {
+            (high..Value::ALL.len())
+                .map(|v| class.with_values(v, v))
+                .collect()
+        } else {
+            (low..high).map(|v| class.with_values(high, v)).collect()
+        }
+    } else if let Some((top, bottom)) = body.split_once('-') {
+        let top: HandClass = top.parse()?;
+        let bottom: HandClass = bottom.parse()?;
+        if top.suited != bottom.suited {
+            return Err(invalid());
+        }
+        let (top_high, top_low) = (top.high.index(), top.low.index());
+        let (bottom_high, bottom_low) = (bottom.high.index(), bottom.low.index());
+        if top.is_pair() && bottom.is_pair() {
+            let (low, high) = (top_low.min(bottom_low), top_low.max(bottom_low));
+            (low..=high).map(|v| top.with_values(v, v)).collect()
+        } else if top.high == bottom.high && !top.is_pair() && !bottom.is_pair() {
+            let (low, high) = (top_low.min(bottom_low), top_low.max(bottom_low));
+            (low..=high).map(|v| top.with_values(top_high, v)).collect()
+        } else if top_high - top_low == bottom_high - bottom_low && !top.is_pair() {
+            let gap = top_high - top_low;
+            let (low, high) = (top_low.min(bottom_low), top_low.max(bottom_low));
+            (low..=high).map(|v| top.with_values(v + gap, v)).collect()
+        } else {
+            return Err(invalid());
+        }
+    } else {
+        vec![body.parse()?]
+    };
+    Ok(classes.iter().flat_map(|class| class.combos()).collect())
+}
+
+// "AsKs" style exact combo, None if the token isn't one.
+fn exact_combo(body: &str) -> Option<Vec<PlayingCard>> {
+    if body.len() != 4 || !body.is_ascii() {
+        return None;
+    }
+    let first: PlayingCard = body[..2].parse().ok()?;
+    let second: PlayingCard = body[2..].parse().ok()?;
+    if first == second || first.is_joker() || second.is_joker() {
+        return None;
+    }
+    Some(vec![first, second])
+}
+
+fn parse_weight(weight: &str) -> Result<f64, RangeError> {
+    match weight.trim().parse::<f64>() {
+        Ok(w) if (0.0..=FULL_WEIGHT).contains(&w) => Ok(w),
+        _ => Err(RangeError::InvalidWeight(weight.to_string())),
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn count(range: &str) -> usize {
+        range.parse::<Range>().unwrap().len()
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(count("AA"), 6);
+        assert_eq!(count("AKs"), 4);
+        assert_eq!(count("AKo"), 12);
+        assert_eq!(count("KA"), 16);
+        assert_eq!(count("QQ+"), 18);
+        assert_eq!(count("A2s+"), 48);
+        assert_eq!(count("KTo+"), 36);
+        assert_eq!(count("22-55"), 24);
+        assert_eq!(count("76s-54s"), 12);
+        assert_eq!(count("A5s-A2s"), 16);
+        assert_eq!(count("AsKs"), 1);
+        assert_eq!(count("QQ+, AKs, 76s-54s, A5o"), 18 + 4 + 12 + 12);
+        // Listed twice, still one combo each.
+        assert_eq!(count("AA, AsAh, AA"), 6);
+
+        assert_eq!("".parse::<Range>(), Err(RangeError::Empty));
+        assert_eq!(
+            "AAs".parse::<Range>(),
+            Err(RangeError::InvalidToken("AAs".to_string()))
+        );
+        assert_eq!(
+            "AKs-QJo".parse::<Range>(),
+            Err(RangeError::InvalidToken("AKs-QJo".to_string()))
+        );
+        assert_eq!(
+            "AKs:2".parse::<Range>(),
+            Err(RangeError::InvalidWeight("2".to_string()))
+        );
+    }
+
+    #[test]
+    fn weight_and_blocker_test() {
+        let range: Range = "AA, AKs:0.5, AsKs:0.25".parse().unwrap();
+        assert_eq!(range.len(), 10);
+        assert!((range.total_weight() - (6.0 + 1.5 + 0.25)).abs() < 1e-9);
+
+        let known = CardSet::from_iter(["As".parse().unwrap()]);
+        let live = range.without_blocked(known);
+        // Three aces pairs and three ace-king combos are left.
+        assert_eq!(live.len(), 6);
+        assert!(live.get_combos().iter().all(|c| !c.is_blocked_by(known)));
+    }
+}
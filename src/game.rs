@@ -0,0 +1,8 @@
+pub mod betting;
+pub mod draw;
+pub mod error;
+pub mod holdem;
+pub mod pot;
+pub mod seats;
+pub mod showdown;
+pub mod stud;
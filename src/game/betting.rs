@@ -0,0 +1,750 @@
+use core::fmt;
+
+use crate::game::pot::Pot;
+
+// CONSTANTS
+// Smallest bet or raise, so a zero big blind can't allow raises of nothing.
+const MIN_BET: u32 = 1;
+
+///Something a seat can do when it's their turn to bet.
+///Bet and Raise amounts are the seat's total bet for the street ("raise to").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Fold,
+    Check,
+    Call,
+    Bet(u32),
+    Raise(u32),
+    AllIn,
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Fold => write!(f, "folds"),
+            Action::Check => write!(f, "checks"),
+            Action::Call => write!(f, "calls"),
+            Action::Bet(amount) => write!(f, "bets {}", amount),
+            Action::Raise(amount) => write!(f, "raises to {}", amount),
+            Action::AllIn => write!(f, "goes all in"),
+        }
+    }
+}
+
+///How much a seat is allowed to bet or raise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Limit {
+    //Anything from the minimum raise up to the whole stack.
+    #[default]
+    NoLimit,
+    //Anything from the minimum raise up to the size of the pot after calling.
+    PotLimit,
+    //Bets and raises are exactly one bet unit, at most `cap` of them a street
+    //(the opening bet counts).
+    FixedLimit {
+        cap: u32,
+    },
+}
+
+///Something the seat to act may do right now. Bet and Raise ranges are the
+///street totals allowed ("raise to"), max being all in when the stack runs out first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegalAction {
+    Fold,
+    Check,
+    Call(u32),
+    Bet { min: u32, max: u32 },
+    Raise { min: u32, max: u32 },
+}
+
+impl fmt::Display for LegalAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LegalAction::Fold => write!(f, "fold"),
+            LegalAction::Check => write!(f, "check"),
+            LegalAction::Call(amount) => write!(f, "call {}", amount),
+            LegalAction::Bet { min, max } if min == max => write!(f, "bet {}", min),
+            LegalAction::Bet { min, max } => write!(f, "bet {}-{}", min, max),
+            LegalAction::Raise { min, max } if min == max => write!(f, "raise {}", min),
+            LegalAction::Raise { min, max } => write!(f, "raise {}-{}", min, max),
+        }
+    }
+}
+
+///Forced bets and betting limit for a hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stakes {
+    pub small_blind: u32,
+    pub big_blind: u32,
+    pub ante: u32,
+    pub bring_in: u32,
+    pub limit: Limit,
+}
+
+impl Stakes {
+    //No limit blinds without an ante.
+    pub fn blinds(small_blind: u32, big_blind: u32) -> Stakes {
+        Stakes {
+            small_blind,
+            big_blind,
+            ante: 0,
+            bring_in: 0,
+            limit: Limit::NoLimit,
+        }
+    }
+
+    //Fixed limit stud: antes, a bring-in, and `small_bet` as the bet unit
+    //(the big bet from fifth street on is double).
+    pub fn stud(ante: u32, bring_in: u32, small_bet: u32) -> Stakes {
+        Stakes {
+            small_blind: 0,
+            big_blind: small_bet,
+            ante,
+            bring_in,
+            limit: Limit::FixedLimit { cap: 4 },
+        }
+    }
+
+    //Bet unit on the later streets. Fixed limit doubles it, the others keep the big blind.
+    pub fn big_bet(&self) -> u32 {
+        match self.limit {
+            Limit::FixedLimit { .. } => self.big_blind * 2,
+            _ => self.big_blind,
+        }
+    }
+}
+
+//Small and big blind seats for a given button. Heads up the button posts the
+//small blind, otherwise the two seats to its left do.
+pub fn blind_seats(button: usize, seats: usize) -> (usize, usize) {
+    if seats == 2 {
+        (button, (button + 1) % seats)
+    } else {
+        ((button + 1) % seats, (button + 2) % seats)
+    }
+}
+
+///Reasons a betting action gets refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BettingError {
+    RoundComplete,
+    NotYourTurn { expected: usize },
+    CannotCheck { to_call: u32 },
+    NothingToCall,
+    BetAlreadyOpen,
+    NothingToRaise,
+    BelowMinimum { min: u32 },
+    AboveMaximum { max: u32 },
+    NotEnoughChips { stack: u32 },
+    RaiseCapReached { cap: u32 },
+    ActionNotReopened,
+}
+
+impl fmt::Display for BettingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BettingError::RoundComplete => write!(f, "Betting round is already over"),
+            BettingError::NotYourTurn { expected } => {
+                write!(f, "It's seat {}'s turn to act", expected)
+            }
+            BettingError::CannotCheck { to_call } => {
+                write!(f, "Can't check, {} to call", to_call)
+            }
+            BettingError::NothingToCall => write!(f, "Nothing to call"),
+            BettingError::BetAlreadyOpen => write!(f, "There's already a bet, raise instead"),
+            BettingError::NothingToRaise => write!(f, "Nothing to raise, bet instead"),
+            BettingError::BelowMinimum { min } => write!(f, "Has to be at least {}", min),
+            BettingError::AboveMaximum { max } => write!(f, "Can't be more than {}", max),
+            BettingError::NotEnoughChips { stack } => write!(f, "Only {} chips left", stack),
+            BettingError::RaiseCapReached { cap } => {
+                write!(f, "Betting is capped at {} bets this street", cap)
+            }
+            BettingError::ActionNotReopened => {
+                write!(f, "Only a short all in since you acted, call or fold")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BettingError {}
+
+///Betting engine for one hand. Owns the stacks for the length of the hand,
+///tracks each street's bets and everything put in the pot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Betting {
+    stacks: Vec<u32>,
+    bets: Vec<u32>,
+    pot: Pot,
+    folded: Vec<bool>,
+    acted: Vec<bool>,
+    current_bet: u32,
+    min_raise: u32,
+    big_blind: u32,
+    bet_unit: u32,
+    limit: Limit,
+    raises: u32,
+    to_act: Option<usize>,
+}
+
+impl Betting {
+    pub fn new(stacks: Vec<u32>, big_blind: u32) -> Betting {
+        Betting::with_limit(stacks, big_blind, Limit::NoLimit)
+    }
+
+    //Bet units start at the big blind (at least a chip), see set_bet_unit for the
+    //bigger streets.
+    pub fn with_limit(stacks: Vec<u32>, big_blind: u32, limit: Limit) -> Betting {
+        let seats = stacks.len();
+        Betting {
+            stacks,
+            bets: vec![0; seats],
+            pot: Pot::new(seats),
+            folded: vec![false; seats],
+            acted: vec![false; seats],
+            current_bet: 0,
+            min_raise: big_blind.max(MIN_BET),
+            big_blind,
+            bet_unit: big_blind.max(MIN_BET),
+            limit,
+            raises: 0,
+            to_act: None,
+        }
+    }
+
+    // -------------------------
+    // Getters
+    // -------------------------
+
+    //Just a getter.
+    pub fn get_stacks(&self) -> &Vec<u32> {
+        &self.stacks
+    }
+    //Just a getter.
+    pub fn get_bets(&self) -> &Vec<u32> {
+        &self.bets
+    }
+    //Just a getter.
+    pub fn get_pot(&self) -> &Pot {
+        &self.pot
+    }
+    //Standard getter.
+    pub fn get_current_bet(&self) -> u32 {
+        self.current_bet
+    }
+    //Standard getter.
+    pub fn get_min_raise(&self) -> u32 {
+        self.min_raise
+    }
+    //Standard getter.
+    pub fn get_big_blind(&self) -> u32 {
+        self.big_blind
+    }
+    //Standard getter.
+    pub fn get_limit(&self) -> Limit {
+        self.limit
+    }
+    //Seat whose turn it is, None once the street is done.
+    pub fn get_to_act(&self) -> Option<usize> {
+        self.to_act
+    }
+    pub fn is_round_complete(&self) -> bool {
+        self.to_act.is_none()
+    }
+    pub fn has_folded(&self, seat: usize) -> bool {
+        self.folded[seat]
+    }
+    pub fn is_all_in(&self, seat: usize) -> bool {
+        !self.folded[seat] && self.stacks[seat] == 0
+    }
+    //Seats that haven't folded.
+    pub fn players_in_hand(&self) -> Vec<usize> {
+        (0..self.stacks.len())
+            .filter(|&s| !self.folded[s])
+            .collect()
+    }
+    //Chips the seat still has to put in to match the current bet (may be more than its stack).
+    pub fn amount_to_call(&self, seat: usize) -> u32 {
+        self.current_bet - self.bets[seat]
+    }
+
+    // -------------------------
+    // Betting Flow
+    // -------------------------
+
+    //Size of a bet (and the smallest raise) from the next street on. Fixed limit
+    //games raise it to the big bet for the later streets.
+    pub fn set_bet_unit(&mut self, unit: u32) {
+        self.bet_unit = unit.max(MIN_BET);
+    }
+
+    //Starts a new street. Bets go back to zero and action starts at the first seat,
+    //from `first` onwards, that still has a decision to make.
+    pub fn start_round(&mut self, first: usize) {
+        self.bets = vec![0; self.stacks.len()];
+        self.acted = vec![false; self.stacks.len()];
+        self.current_bet = 0;
+        self.min_raise = self.bet_unit;
+        self.raises = 0;
+        self.to_act = self.next_to_act(first);
+    }
+
+    //Everything the seat to act may do, with the amounts allowed. Empty once the
+    //street is over.
+    pub fn legal_actions(&self) -> Vec<LegalAction> {
+        let Some(seat) = self.to_act else {
+            return Vec::new();
+        };
+        let to_call = self.amount_to_call(seat);
+        let mut actions = vec![LegalAction::Fold];
+        if to_call == 0 {
+            actions.push(LegalAction::Check);
+        } else {
+            actions.push(LegalAction::Call(to_call.min(self.stacks[seat])));
+        }
+        if let Ok((min, max)) = self.raise_range(seat) {
+            if self.current_bet == 0 {
+                actions.push(LegalAction::Bet { min, max });
+            } else {
+                actions.push(LegalAction::Raise { min, max });
+            }
+        }
+        actions
+    }
+
+    //Posts a blind. Counts as the seat's bet for the street but not as acting,
+    //so the big blind still gets its option. Short stacks post what they have.
+    pub fn post_blind(&mut self, seat: usize, amount: u32) -> u32 {
+        let posted = self.pay(seat, amount);
+        if self.bets[seat] > self.current_bet {
+            self.current_bet = self.bets[seat];
+            // The blind is the street's opening bet as far as raise caps go.
+            self.raises = 1;
+        }
+        self.refresh_to_act();
+        posted
+    }
+
+    //Posts a stud bring-in. Unlike a blind it counts as the seat's action, and the
+    //next seat may complete it to a full bet. Action moves on to the next seat.
+    pub fn post_bring_in(&mut self, seat: usize, amount: u32) -> u32 {
+        let posted = self.pay(seat, amount);
+        self.current_bet = self.current_bet.max(self.bets[seat]);
+        self.min_raise = if self.current_bet < self.bet_unit {
+            self.bet_unit - self.current_bet
+        } else {
+            self.bet_unit
+        };
+        self.acted[seat] = true;
+        self.raises = 0;
+        self.to_act = self.next_to_act(seat + 1);
+        posted
+    }
+
+    //Posts an ante. Goes straight in the pot without counting towards the bet.
+    pub fn post_ante(&mut self, seat: usize, amount: u32) -> u32 {
+        let posted = amount.min(self.stacks[seat]);
+        self.stacks[seat] -= posted;
+        self.pot.add(seat, posted);
+        self.refresh_to_act();
+        posted
+    }
+
+    //Applies an action for the seat whose turn it is. Returns the chips it put in.
+    pub fn apply(&mut self, seat: usize, action: Action) -> Result<u32, BettingError> {
+        let expected = self.to_act.ok_or(BettingError::RoundComplete)?;
+        if seat != expected {
+            return Err(BettingError::NotYourTurn { expected });
+        }
+        let all_in_to = self.bets[seat] + self.stacks[seat];
+        let paid = match action {
+            Action::Fold => {
+                self.folded[seat] = true;
+                0
+            }
+            Action::Check => {
+                if self.bets[seat] < self.current_bet {
+                    return Err(BettingError::CannotCheck {
+                        to_call: self.amount_to_call(seat),
+                    });
+                }
+                0
+            }
+            Action::Call => {
+                if self.bets[seat] >= self.current_bet {
+                    return Err(BettingError::NothingToCall);
+                }
+                self.pay(seat, self.amount_to_call(seat))
+            }
+            Action::Bet(to) => {
+                if self.current_bet > 0 {
+                    return Err(BettingError::BetAlreadyOpen);
+                }
+                self.raise_to(seat, to)?
+            }
+            Action::Raise(to) => {
+                if self.current_bet == 0 {
+                    return Err(BettingError::NothingToRaise);
+                }
+                self.raise_to(seat, to)?
+            }
+            Action::AllIn => {
+                if all_in_to <= self.current_bet {
+                    self.pay(seat, self.stacks[seat])
+                } else {
+                    self.raise_to(seat, all_in_to)?
+                }
+            }
+        };
+        self.acted[seat] = true;
+        self.to_act = self.next_to_act(seat + 1);
+        Ok(paid)
+    }
+
+    //Gives chips back to a seat (pot winnings, returned bets).
+    pub fn award(&mut self, seat: usize, amount: u32) {
+        self.stacks[seat] += amount;
+    }
+
+    // Smallest and largest street total the seat could bet or raise to, or why
+    // it can't raise at all. A stack too short for the minimum can still go all in.
+    fn raise_range(&self, seat: usize) -> Result<(u32, u32), BettingError> {
+        let all_in_to = self.bets[seat] + self.stacks[seat];
+        if all_in_to <= self.current_bet {
+            return Err(BettingError::NotEnoughChips {
+                stack: self.stacks[seat],
+            });
+        }
+        // Acted already and nothing but short all ins since, so only call or fold.
+        if self.acted[seat] {
+            return Err(BettingError::ActionNotReopened);
+        }
+        let min = self.current_bet + self.min_raise;
+        let max = match self.limit {
+            Limit::NoLimit => all_in_to,
+            Limit::PotLimit => self.current_bet + self.pot.total() + self.amount_to_call(seat),
+            Limit::FixedLimit { cap } => {
+                if self.raises >= cap {
+                    return Err(BettingError::RaiseCapReached { cap });
+                }
+                min
+            }
+        };
+        Ok((min.min(all_in_to), max.max(min).min(all_in_to)))
+    }
+
+    // Bets or raises the seat's street total to `to`. Anything under the minimum
+    // is only allowed as an all in. A full size raise reopens the action for everyone.
+    fn raise_to(&mut self, seat: usize, to: u32) -> Result<u32, BettingError> {
+        let cost = to.saturating_sub(self.bets[seat]);
+        if cost > self.stacks[seat] {
+            return Err(BettingError::NotEnoughChips {
+                stack: self.stacks[seat],
+            });
+        }
+        let (min, max) = self.raise_range(seat)?;
+        if to < min {
+            return Err(BettingError::BelowMinimum { min });
+        }
+        if to > max {
+            return Err(BettingError::AboveMaximum { max });
+        }
+        let raise = to - self.current_bet;
+        if raise >= self.min_raise {
+            // Completing a bring-in is a full raise, but later raises are still a full unit.
+            self.min_raise = raise.max(self.bet_unit);
+            self.acted = vec![false; self.stacks.len()];
+        }
+        self.raises += 1;
+        self.current_bet = to;
+        Ok(self.pay(seat, cost))
+    }
+
+    fn pay(&mut self, seat: usize, amount: u32) -> u32 {
+        let paid = amount.min(self.stacks[seat]);
+        self.stacks[seat] -= paid;
+        self.bets[seat] += paid;
+        self.pot.add(seat, paid);
+        paid
+    }
+
+    fn refresh_to_act(&mut self) {
+        if let Some(seat) = self.to_act {
+            self.to_act = self.next_to_act(seat);
+        }
+    }
+
+    // Still in, still has chips, and either hasn't acted since the last full raise
+    // or is short of the current bet.
+    fn needs_action(&self, seat: usize) -> bool {
+        !self.folded[seat]
+            && self.stacks[seat] > 0
+            && (!self.acted[seat] || self.bets[seat] < self.current_bet)
+    }
+
+    // First seat from `from` (wrapping round) that still has to act, if the street
+    // isn't over. It's over once one player is left, or nobody with chips owes
+    // a decision.
+    fn next_to_act(&self, from: usize) -> Option<usize> {
+        let seats = self.stacks.len();
+        if self.players_in_hand().len() <= 1 {
+            return None;
+        }
+        let can_act: Vec<usize> = (0..seats)
+            .filter(|&s| !self.folded[s] && self.stacks[s] > 0)
+            .collect();
+        // Nobody left to bet against.
+        if can_act.len() == 1 && self.bets[can_act[0]] >= self.current_bet {
+            return None;
+        }
+        (0..seats)
+            .map(|i| (from + i) % seats)
+            .find(|&s| self.needs_action(s))
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Three handed, blinds 1/2 posted by seats 0 and 1, seat 2 first to act.
+    fn blinds_posted() -> Betting {
+        let mut betting = Betting::new(vec![100, 100, 100], 2);
+        betting.start_round(2);
+        betting.post_blind(0, 1);
+        betting.post_blind(1, 2);
+        betting
+    }
+
+    #[test]
+    fn blinds_and_option_test() {
+        let mut betting = blinds_posted();
+        assert_eq!(betting.get_to_act(), Some(2));
+        assert_eq!(betting.get_pot().total(), 3);
+        assert_eq!(betting.apply(2, Action::Call), Ok(2));
+        assert_eq!(betting.apply(0, Action::Call), Ok(1));
+        // Big blind still gets the option.
+        assert_eq!(betting.get_to_act(), Some(1));
+        assert_eq!(betting.apply(1, Action::Check), Ok(0));
+        assert!(betting.is_round_complete());
+        assert_eq!(betting.get_pot().total(), 6);
+
+        betting.start_round(0);
+        assert_eq!(betting.get_to_act(), Some(0));
+        assert_eq!(betting.apply(0, Action::Bet(10)), Ok(10));
+        assert_eq!(betting.apply(1, Action::Fold), Ok(0));
+        assert_eq!(betting.apply(2, Action::Raise(30)), Ok(30));
+        assert_eq!(betting.apply(0, Action::Call), Ok(20));
+        assert!(betting.is_round_complete());
+        assert_eq!(betting.players_in_hand(), vec![0, 2]);
+        assert_eq!(betting.get_stacks(), &vec![68, 98, 68]);
+    }
+
+    #[test]
+    fn illegal_actions_test() {
+        let mut betting = blinds_posted();
+        assert_eq!(
+            betting.apply(0, Action::Call),
+            Err(BettingError::NotYourTurn { expected: 2 })
+        );
+        assert_eq!(
+            betting.apply(2, Action::Check),
+            Err(BettingError::CannotCheck { to_call: 2 })
+        );
+        assert_eq!(
+            betting.apply(2, Action::Bet(10)),
+            Err(BettingError::BetAlreadyOpen)
+        );
+        assert_eq!(
+            betting.apply(2, Action::Raise(3)),
+            Err(BettingError::BelowMinimum { min: 4 })
+        );
+        assert_eq!(
+            betting.apply(2, Action::Raise(500)),
+            Err(BettingError::NotEnoughChips { stack: 100 })
+        );
+        betting.apply(2, Action::Raise(6)).unwrap();
+        // Last raise was 4, so the next one has to go to at least 10.
+        assert_eq!(
+            betting.apply(0, Action::Raise(9)),
+            Err(BettingError::BelowMinimum { min: 10 })
+        );
+
+        betting.start_round(0);
+        assert_eq!(
+            betting.apply(0, Action::Raise(10)),
+            Err(BettingError::NothingToRaise)
+        );
+        assert_eq!(
+            betting.apply(0, Action::Call),
+            Err(BettingError::NothingToCall)
+        );
+        assert_eq!(
+            betting.apply(0, Action::Bet(1)),
+            Err(BettingError::BelowMinimum { min: 2 })
+        );
+    }
+
+    #[test]
+    fn all_in_test() {
+        let mut betting = Betting::new(vec![100, 5, 100], 2);
+        betting.start_round(0);
+        betting.apply(0, Action::Bet(20)).unwrap();
+        // Short stack calls all in for less.
+        assert_eq!(betting.apply(1, Action::AllIn), Ok(5));
+        assert!(betting.is_all_in(1));
+        assert_eq!(betting.apply(2, Action::AllIn), Ok(100));
+        assert_eq!(betting.get_current_bet(), 100);
+        assert_eq!(betting.apply(0, Action::Call), Ok(80));
+        assert!(betting.is_round_complete());
+
+        // Nobody left with chips to act on the next street.
+        betting.start_round(0);
+        assert!(betting.is_round_complete());
+        assert_eq!(betting.get_pot().total(), 205);
+    }
+
+    #[test]
+    fn legal_actions_test() {
+        let mut betting = blinds_posted();
+        assert_eq!(
+            betting.legal_actions(),
+            vec![
+                LegalAction::Fold,
+                LegalAction::Call(2),
+                LegalAction::Raise { min: 4, max: 100 }
+            ]
+        );
+        betting.apply(2, Action::Call).unwrap();
+        betting.apply(0, Action::Call).unwrap();
+        // Big blind's option.
+        assert_eq!(
+            betting.legal_actions(),
+            vec![
+                LegalAction::Fold,
+                LegalAction::Check,
+                LegalAction::Raise { min: 4, max: 100 }
+            ]
+        );
+        betting.apply(1, Action::Check).unwrap();
+        assert!(betting.legal_actions().is_empty());
+
+        betting.start_round(0);
+        assert_eq!(
+            betting.legal_actions(),
+            vec![
+                LegalAction::Fold,
+                LegalAction::Check,
+                LegalAction::Bet { min: 2, max: 98 }
+            ]
+        );
+    }
+
+    #[test]
+    fn zero_big_blind_test() {
+        // Nothing to post, but a bet or raise still has to be at least a chip.
+        let mut betting = Betting::new(vec![10, 10], 0);
+        betting.start_round(0);
+        assert_eq!(betting.get_min_raise(), 1);
+        assert_eq!(
+            betting.legal_actions()[2],
+            LegalAction::Bet { min: 1, max: 10 }
+        );
+        assert_eq!(
+            betting.apply(0, Action::Bet(0)),
+            Err(BettingError::BelowMinimum { min: 1 })
+        );
+        betting.apply(0, Action::Bet(1)).unwrap();
+        assert_eq!(
+            betting.legal_actions()[2],
+            LegalAction::Raise { min: 2, max: 10 }
+        );
+
+        betting.set_bet_unit(0);
+        betting.start_round(0);
+        assert_eq!(betting.get_min_raise(), 1);
+    }
+
+    #[test]
+    fn pot_limit_test() {
+        let mut betting = Betting::with_limit(vec![100, 100, 100], 2, Limit::PotLimit);
+        betting.start_round(2);
+        betting.post_blind(0, 1);
+        betting.post_blind(1, 2);
+        // Pot of 3 plus the 2 to call, on top of the 2 already bet.
+        assert_eq!(
+            betting.legal_actions()[2],
+            LegalAction::Raise { min: 4, max: 7 }
+        );
+        assert_eq!(
+            betting.apply(2, Action::Raise(8)),
+            Err(BettingError::AboveMaximum { max: 7 })
+        );
+        assert_eq!(
+            betting.apply(2, Action::AllIn),
+            Err(BettingError::AboveMaximum { max: 7 })
+        );
+        betting.apply(2, Action::Raise(7)).unwrap();
+        assert_eq!(
+            betting.legal_actions()[2],
+            LegalAction::Raise { min: 12, max: 23 }
+        );
+    }
+
+    #[test]
+    fn fixed_limit_test() {
+        let mut betting = Betting::with_limit(vec![100, 100, 100], 2, Limit::FixedLimit { cap: 4 });
+        betting.start_round(2);
+        betting.post_blind(0, 1);
+        betting.post_blind(1, 2);
+        assert_eq!(
+            betting.apply(2, Action::Raise(5)),
+            Err(BettingError::AboveMaximum { max: 4 })
+        );
+        betting.apply(2, Action::Raise(4)).unwrap();
+        betting.apply(0, Action::Raise(6)).unwrap();
+        betting.apply(1, Action::Raise(8)).unwrap();
+        // Blind plus three raises is the cap.
+        assert_eq!(
+            betting.legal_actions(),
+            vec![LegalAction::Fold, LegalAction::Call(4)]
+        );
+        assert_eq!(
+            betting.apply(2, Action::Raise(10)),
+            Err(BettingError::RaiseCapReached { cap: 4 })
+        );
+        betting.apply(2, Action::Call).unwrap();
+        betting.apply(0, Action::Call).unwrap();
+        assert!(betting.is_round_complete());
+
+        // Later streets bet the big bet.
+        betting.set_bet_unit(4);
+        betting.start_round(0);
+        assert_eq!(
+            betting.legal_actions()[2],
+            LegalAction::Bet { min: 4, max: 4 }
+        );
+    }
+
+    #[test]
+    fn incomplete_all_in_test() {
+        let mut betting = Betting::new(vec![100, 100, 13], 2);
+        betting.start_round(0);
+        betting.apply(0, Action::Bet(10)).unwrap();
+        betting.apply(1, Action::Call).unwrap();
+        // Only 3 more than the bet, short of a full raise.
+        assert_eq!(betting.apply(2, Action::AllIn), Ok(13));
+        assert_eq!(
+            betting.legal_actions(),
+            vec![LegalAction::Fold, LegalAction::Call(3)]
+        );
+        assert_eq!(
+            betting.apply(0, Action::Raise(30)),
+            Err(BettingError::ActionNotReopened)
+        );
+        betting.apply(0, Action::Call).unwrap();
+        betting.apply(1, Action::Call).unwrap();
+        assert!(betting.is_round_complete());
+        assert_eq!(betting.get_pot().total(), 39);
+    }
+}
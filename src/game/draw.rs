@@ -0,0 +1,506 @@
+use crate::deck::Deck;
+use crate::game::betting::{Action, Betting, LegalAction, Stakes};
+use crate::game::error::GameError;
+use crate::game::pot::OddChipRule;
+use crate::game::seats::Seats;
+use crate::game::showdown::{ShowdownEntry, showdown};
+use crate::hand::Hand;
+use crate::hand::card::PlayingCard;
+use crate::hand::error::CardError;
+use crate::hand::evaluator::best_wild_hand;
+use crate::hand::wild::WildCards;
+use crate::player::Player;
+use strategy::Strategy;
+
+pub mod advisor;
+pub mod strategy;
+
+// CONSTANTS
+pub const MIN_SEATS: usize = 2;
+pub const MAX_SEATS: usize = 6;
+pub const DEFAULT_MAX_DISCARD: usize = 3;
+const HAND_SIZE: usize = 5;
+const DECK_SIZE: usize = 52;
+
+///Where a five card draw hand is up to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawPhase {
+    Dealing,
+    PreDrawBetting,
+    Drawing,
+    PostDrawBetting,
+    Showdown,
+    Complete,
+}
+
+///Five card draw: deal five each, a betting round, one round of discarding and
+///drawing replacements from the same deck, a second betting round, then every
+///hand still in is ranked and the pot is paid out.
+#[derive(Clone, Debug)]
+pub struct DrawGame {
+    seats: Seats,
+    hands: Vec<Hand>,
+    deck: Deck,
+    muck: Vec<PlayingCard>,
+    max_discard: usize,
+    wild: WildCards,
+    drawn: Vec<bool>,
+    phase: DrawPhase,
+}
+
+impl DrawGame {
+    //Seats are in dealing order, the button starts on the last seat. Refuses seat
+    //counts outside 2-6, and discard limits that could run the deck out if every
+    //seat used them. Stacks come from the players.
+    pub fn new(
+        players: Vec<Player>,
+        deck: Deck,
+        max_discard: usize,
+        stakes: Stakes,
+    ) -> Result<DrawGame, GameError> {
+        let seats = Seats::new(players, stakes, MIN_SEATS, MAX_SEATS)?;
+        let needed = seats.count() * (HAND_SIZE + max_discard);
+        if needed > DECK_SIZE {
+            return Err(GameError::NotEnoughCards { needed });
+        }
+        Ok(DrawGame {
+            hands: vec![Hand::default(); seats.count()],
+            deck,
+            muck: Vec::new(),
+            max_discard,
+            wild: WildCards::new(),
+            drawn: vec![false; seats.count()],
+            phase: DrawPhase::Dealing,
+            seats,
+        })
+    }
+
+    // -------------------------
+    // Getters
+    // -------------------------
+
+    //Just a getter.
+    pub fn get_players(&self) -> &Vec<Player> {
+        self.seats.get_players()
+    }
+    //Just a getter.
+    pub fn get_hands(&self) -> &Vec<Hand> {
+        &self.hands
+    }
+    //Standard getter.
+    pub fn get_wild_cards(&self) -> &WildCards {
+        &self.wild
+    }
+    //Standard getter.
+    pub fn get_max_discard(&self) -> usize {
+        self.max_discard
+    }
+    //Just a getter.
+    pub fn get_deck(&self) -> &Deck {
+        &self.deck
+    }
+    //Just a getter.
+    pub fn get_muck(&self) -> &Vec<PlayingCard> {
+        &self.muck
+    }
+    //Just a getter.
+    pub fn get_betting(&self) -> &Betting {
+        self.seats.get_betting()
+    }
+    //Standard getter.
+    pub fn get_phase(&self) -> DrawPhase {
+        self.phase
+    }
+    //Standard getter.
+    pub fn get_button(&self) -> usize {
+        self.seats.get_button()
+    }
+    //Seat whose turn it is to bet, if a betting round is running.
+    pub fn to_act(&self) -> Option<usize> {
+        match self.phase {
+            DrawPhase::PreDrawBetting | DrawPhase::PostDrawBetting => self.seats.to_act(),
+            _ => None,
+        }
+    }
+
+    //What the seat to act may do, empty when nobody is betting.
+    pub fn legal_actions(&self) -> Vec<LegalAction> {
+        match self.to_act() {
+            Some(_) => self.seats.legal_actions(),
+            None => Vec::new(),
+        }
+    }
+
+    //See Seats::set_button. Only allowed before the deal.
+    pub fn set_button(&mut self, seat: usize) -> Result<(), GameError> {
+        if self.phase != DrawPhase::Dealing {
+            return Err(GameError::WrongPhase);
+        }
+        self.seats.set_button(seat)
+    }
+
+    //See Seats::set_blinds. Only allowed before the deal.
+    pub fn set_blinds(&mut self, small: Option<usize>, big: usize) -> Result<(), GameError> {
+        if self.phase != DrawPhase::Dealing {
+            return Err(GameError::WrongPhase);
+        }
+        self.seats.set_blinds(small, big)
+    }
+
+    //See Seats::set_odd_chip_rule.
+    pub fn set_odd_chip_rule(&mut self, rule: OddChipRule) {
+        self.seats.set_odd_chip_rule(rule);
+    }
+
+    //Which cards are wild. Jokers always are (if the deck has them), on top of
+    //that eg. deuces. Only allowed before the deal.
+    pub fn set_wild_cards(&mut self, wild: WildCards) -> Result<(), GameError> {
+        if self.phase != DrawPhase::Dealing {
+            return Err(GameError::WrongPhase);
+        }
+        self.wild = wild;
+        Ok(())
+    }
+
+    // -------------------------
+    // Game Flow
+    // -------------------------
+
+    //Posts antes and blinds, deals five cards to every seat one card at a time
+    //starting left of the button, and opens the pre-draw betting.
+    pub fn deal(&mut self) -> Result<(), GameError> {
+        if self.phase != DrawPhase::Dealing {
+            return Err(GameError::WrongPhase);
+        }
+        for _i in 0..HAND_SIZE {
+            for seat in self.seats.deal_order() {
+                self.deck.deal_to(&mut self.hands[seat])?;
+            }
+        }
+        self.check_conservation();
+
+        self.seats.post_blinds();
+        self.phase = DrawPhase::PreDrawBetting;
+        self.advance();
+        Ok(())
+    }
+
+    //Betting action for the seat whose turn it is.
+    pub fn act(&mut self, seat: usize, action: Action) -> Result<u32, GameError> {
+        if self.to_act().is_none() {
+            return Err(GameError::WrongPhase);
+        }
+        let paid = self.seats.act(seat, action)?;
+        self.advance();
+        Ok(paid)
+    }
+
+    //Seats still in the hand, starting left of the button.
+    pub fn seats_in_hand(&self) -> Vec<usize> {
+        self.seats.seats_in_hand()
+    }
+
+    //Discards the cards at the given indexes for a seat and deals replacements.
+    //Returns the discarded cards.
+    pub fn draw(
+        &mut self,
+        seat: usize,
+        mut indexes: Vec<usize>,
+    ) -> Result<Vec<PlayingCard>, GameError> {
+        if seat >= self.hands.len() {
+            return Err(GameError::SeatOutOfRange(seat));
+        }
+        if self.phase != DrawPhase::Drawing {
+            return Err(GameError::WrongPhase);
+        }
+        if self.seats.get_betting().has_folded(seat) {
+            return Err(GameError::SeatNotInHand(seat));
+        }
+        if self.drawn[seat] {
+            return Err(GameError::AlreadyDrawn(seat));
+        }
+        indexes.sort();
+        indexes.dedup();
+        if indexes.len() > self.max_discard {
+            return Err(GameError::TooManyDiscards {
+                requested: indexes.len(),
+                max: self.max_discard,
+            });
+        }
+        // Nothing leaves the hand unless every replacement can be dealt, or the
+        // discards would be lost with the hand left short.
+        if self.deck.remaining() < indexes.len() {
+            return Err(CardError::DeckEmpty.into());
+        }
+        let discarded = self.hands[seat].group_discard(indexes)?;
+        for _i in 0..discarded.len() {
+            self.deck.deal_to(&mut self.hands[seat])?;
+        }
+        self.muck.extend(discarded.iter().copied());
+        self.drawn[seat] = true;
+        self.check_conservation();
+        self.advance();
+        Ok(discarded)
+    }
+
+    //Draw for a computer seat, throwing whatever the strategy picks.
+    pub fn computer_draw(
+        &mut self,
+        seat: usize,
+        strategy: &mut dyn Strategy,
+    ) -> Result<Vec<PlayingCard>, GameError> {
+        if seat >= self.hands.len() {
+            return Err(GameError::SeatOutOfRange(seat));
+        }
+        let indexes = strategy.discards(self, seat);
+        self.draw(seat, indexes)
+    }
+
+    //The hand a seat's five cards play as, wild cards swapped for whatever makes
+    //the best hand. Same as the dealt hand when there are no wild cards in it.
+    pub fn played_hand(&self, seat: usize) -> Result<Hand, GameError> {
+        let hand = self
+            .hands
+            .get(seat)
+            .ok_or(GameError::SeatOutOfRange(seat))?;
+        if hand.get_hand().len() < HAND_SIZE {
+            return Err(GameError::WrongPhase);
+        }
+        Ok(best_wild_hand(hand.get_hand(), &self.wild).expect("Draw hands hold five cards."))
+    }
+
+    //Finishing groups among the seats still in, best hand first, ties grouped together.
+    pub fn showdown(&self) -> Vec<Vec<ShowdownEntry>> {
+        let played = self.played_hands();
+        let hands: Vec<(usize, &Hand)> = played.iter().map(|(seat, hand)| (*seat, hand)).collect();
+        showdown(&hands)
+    }
+
+    //Pays out the played hands of the seats still in, see Seats::settle.
+    pub fn settle(&mut self) -> Result<Vec<(usize, u32)>, GameError> {
+        if self.phase != DrawPhase::Showdown {
+            return Err(GameError::WrongPhase);
+        }
+        let played = self.played_hands();
+        let in_hand: Vec<(usize, &Hand)> =
+            played.iter().map(|(seat, hand)| (*seat, hand)).collect();
+        let payouts = self.seats.settle(&in_hand);
+        self.phase = DrawPhase::Complete;
+        Ok(payouts)
+    }
+
+    // Moves the hand on once a betting round or the draw is finished. A hand
+    // where everyone else folded skips straight to the showdown.
+    fn advance(&mut self) {
+        loop {
+            let betting = self.seats.get_betting();
+            let one_left = betting.players_in_hand().len() <= 1;
+            let round_complete = betting.is_round_complete();
+            match self.phase {
+                DrawPhase::PreDrawBetting if one_left => self.phase = DrawPhase::Showdown,
+                DrawPhase::PreDrawBetting if round_complete => self.phase = DrawPhase::Drawing,
+                DrawPhase::Drawing if self.seats_in_hand().iter().all(|&s| self.drawn[s]) => {
+                    self.seats.start_round_left_of_button(true);
+                    self.phase = DrawPhase::PostDrawBetting;
+                }
+                DrawPhase::PostDrawBetting if one_left || round_complete => {
+                    self.phase = DrawPhase::Showdown
+                }
+                _ => return,
+            }
+        }
+    }
+
+    // Played hands for every seat still in.
+    fn played_hands(&self) -> Vec<(usize, Hand)> {
+        self.seats_in_hand()
+            .into_iter()
+            .filter_map(|seat| self.played_hand(seat).ok().map(|hand| (seat, hand)))
+            .collect()
+    }
+
+    fn check_conservation(&self) {
+        let hands: Vec<&Hand> = self.hands.iter().collect();
+        self.deck.debug_check_conservation(&hands, &self.muck);
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hand::card::Value;
+    use crate::hand::hand_type::HandType;
+    use strategy::{StandardStrategy, standard_discards};
+
+    // Seats without chips, so the hand goes straight to the draw.
+    fn players(count: usize) -> Vec<Player> {
+        Player::computers(&vec![0; count])
+    }
+
+    #[test]
+    fn seat_limits_test() {
+        assert!(matches!(
+            DrawGame::new(players(1), Deck::new(), 3, Stakes::default()),
+            Err(GameError::SeatCount { seats: 1, .. })
+        ));
+        assert!(matches!(
+            DrawGame::new(players(7), Deck::new(), 3, Stakes::default()),
+            Err(GameError::SeatCount { seats: 7, .. })
+        ));
+        assert_eq!(
+            DrawGame::new(players(6), Deck::new(), 5, Stakes::default()).err(),
+            Some(GameError::NotEnoughCards { needed: 60 })
+        );
+        assert!(DrawGame::new(players(6), Deck::new(), 3, Stakes::default()).is_ok());
+    }
+
+    #[test]
+    fn deal_and_draw_test() {
+        // Round robin: seat 0 gets the 1st, 3rd, 5th ... cards.
+        let deck = Deck::rigged("As 2c Ad 3c Kh 9h Ks 7h 4c 9d Qs Jh");
+        let mut game = DrawGame::new(players(2), deck, 3, Stakes::default()).unwrap();
+        game.deal().unwrap();
+        assert_eq!(game.get_hands()[0].get_hand_type(), HandType::TwoPair);
+        assert_eq!(game.get_hands()[1].get_hand_type(), HandType::Pair);
+
+        assert_eq!(
+            game.draw(1, vec![0, 1, 2, 3]),
+            Err(GameError::TooManyDiscards {
+                requested: 4,
+                max: 3
+            })
+        );
+        assert_eq!(game.draw(2, vec![]), Err(GameError::SeatOutOfRange(2)));
+
+        // Two pair only throws the kicker.
+        assert_eq!(
+            standard_discards(&game.get_hands()[0], 3, &WildCards::new()).len(),
+            1
+        );
+        let discarded = game.computer_draw(0, &mut StandardStrategy).unwrap();
+        assert_eq!(discarded[0].get_value(), Value::Four);
+        assert_eq!(game.get_hands()[0].get_hand().len(), 5);
+        assert_eq!(game.get_muck().len(), 1);
+
+        // Pair of nines keeps the nines and throws the lowest three.
+        let discarded = game.computer_draw(1, &mut StandardStrategy).unwrap();
+        assert_eq!(discarded.len(), 3);
+        assert!(discarded.iter().all(|c| c.get_value() != Value::Nine));
+
+        assert_eq!(game.showdown()[0][0].get_seat(), 0);
+        assert_eq!(game.get_phase(), DrawPhase::Showdown);
+    }
+
+    #[test]
+    fn short_deck_draw_test() {
+        let mut game = DrawGame::new(players(2), Deck::seeded(4), 3, Stakes::default()).unwrap();
+        game.deal().unwrap();
+        assert_eq!(game.get_phase(), DrawPhase::Drawing);
+        // One card left can't replace two, so the hand is left as it was.
+        let last = game.deck.deal().unwrap();
+        game.deck = Deck::from_order(vec![last]);
+        let before = game.get_hands()[0].clone();
+        assert_eq!(
+            game.draw(0, vec![0, 1]),
+            Err(GameError::Card(CardError::DeckEmpty))
+        );
+        assert_eq!(game.get_hands()[0], before);
+        assert!(game.get_muck().is_empty());
+        assert!(!game.drawn[0]);
+    }
+
+    #[test]
+    fn wild_cards_test() {
+        // Seat 0 has a deuce with kings, seat 1 the black joker with queens.
+        let deck = Deck::rigged("2c Jks Kh Ad Kd Qs 7s Qc 4c 9h");
+        let mut game = DrawGame::new(players(2), deck, 3, Stakes::default()).unwrap();
+        game.set_wild_cards(WildCards::deuces()).unwrap();
+        game.deal().unwrap();
+        assert_eq!(
+            game.set_wild_cards(WildCards::new()),
+            Err(GameError::WrongPhase)
+        );
+        assert_eq!(game.get_hands()[0].get_hand_type(), HandType::Pair);
+        assert_eq!(
+            game.played_hand(0).unwrap().get_hand_type(),
+            HandType::ThreeKind
+        );
+        assert_eq!(
+            game.played_hand(1).unwrap().get_hand_type(),
+            HandType::ThreeKind
+        );
+
+        // The deuce is kept, only the seven and four go.
+        let hand = &game.get_hands()[0];
+        let discards = standard_discards(hand, 3, game.get_wild_cards());
+        assert_eq!(discards.len(), 2);
+        assert!(
+            discards
+                .iter()
+                .all(|&i| hand.get_hand()[i].get_value() != Value::Two)
+        );
+
+        game.draw(0, vec![]).unwrap();
+        game.draw(1, vec![]).unwrap();
+        assert_eq!(game.showdown()[0][0].get_seat(), 0);
+    }
+
+    #[test]
+    fn betting_hand_test() {
+        let players = Player::computers(&[100, 100, 100]);
+        let mut game = DrawGame::new(players, Deck::seeded(3), 3, Stakes::blinds(1, 2)).unwrap();
+        assert_eq!(game.draw(0, vec![]), Err(GameError::WrongPhase));
+        game.deal().unwrap();
+        // Button on seat 2, blinds on 0 and 1, so the button opens.
+        assert_eq!(game.get_phase(), DrawPhase::PreDrawBetting);
+        assert_eq!(game.to_act(), Some(2));
+        game.act(2, Action::Raise(6)).unwrap();
+        game.act(0, Action::Fold).unwrap();
+        game.act(1, Action::Call).unwrap();
+
+        assert_eq!(game.get_phase(), DrawPhase::Drawing);
+        assert_eq!(game.draw(0, vec![]), Err(GameError::SeatNotInHand(0)));
+        game.computer_draw(1, &mut StandardStrategy).unwrap();
+        assert_eq!(game.draw(1, vec![]), Err(GameError::AlreadyDrawn(1)));
+        game.draw(2, vec![]).unwrap();
+
+        assert_eq!(game.get_phase(), DrawPhase::PostDrawBetting);
+        assert_eq!(game.to_act(), Some(1));
+        game.act(1, Action::Check).unwrap();
+        game.act(2, Action::Bet(10)).unwrap();
+        game.act(1, Action::Fold).unwrap();
+
+        assert_eq!(game.get_phase(), DrawPhase::Showdown);
+        assert_eq!(game.settle(), Ok(vec![(2, 23)]));
+        let stacks: Vec<u32> = game.get_players().iter().map(|p| p.get_stack()).collect();
+        assert_eq!(stacks, vec![99, 94, 107]);
+        assert_eq!(game.get_phase(), DrawPhase::Complete);
+    }
+
+    #[test]
+    fn side_pot_hand_test() {
+        let players = Player::computers(&[10, 100, 100]);
+        let mut game = DrawGame::new(players, Deck::seeded(1), 3, Stakes::blinds(1, 2)).unwrap();
+        game.deal().unwrap();
+        game.act(2, Action::Raise(20)).unwrap();
+        game.act(0, Action::AllIn).unwrap();
+        game.act(1, Action::Call).unwrap();
+        for seat in 0..3 {
+            game.draw(seat, vec![]).unwrap();
+        }
+        game.act(1, Action::Check).unwrap();
+        game.act(2, Action::Check).unwrap();
+
+        let folded = [false; 3];
+        let pots = game.get_betting().get_pot().side_pots(&folded);
+        assert_eq!(pots.len(), 2);
+        assert_eq!(pots[0].get_amount(), 30);
+        assert_eq!(pots[1].get_amount(), 20);
+        assert_eq!(pots[1].get_eligible(), &vec![1, 2]);
+        // Short stack takes the main pot, seat 1 beats seat 2 for the side pot.
+        assert_eq!(game.settle(), Ok(vec![(0, 30), (1, 20)]));
+        let stacks: Vec<u32> = game.get_players().iter().map(|p| p.get_stack()).collect();
+        assert_eq!(stacks, vec![30, 100, 80]);
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::hand::Hand;
+use crate::hand::card::PlayingCard;
+use crate::hand::card_set::CardSet;
+use crate::hand::error::CardError;
+use crate::hand::evaluator::for_each_combination;
+use crate::hand::hand_type::HandType;
+use crate::hand::lookup;
+use crate::hand::rank::HandRank;
+
+// Exact draw odds for five card draw. Every card that could come off the deck
+// is tried for every set of discards, which adds up to one pass over all
+// 2,598,960 five card hands for all 32 sets, so it's quick enough to run while
+// someone is deciding.
+
+// CONSTANTS
+const HAND_SIZE: usize = 5;
+// Categories run 1 (high card) to 10 (royal flush), see HandRank::category.
+const CATEGORIES: usize = 11;
+
+// Every five card rank with how many hands sit below it and how many share it,
+// worst first.
+static RANDOM_HANDS: OnceLock<Vec<(HandRank, u64, u64)>> = OnceLock::new();
+
+///Who the win rate is measured against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Opponent {
+    //A random five card hand off a fresh deck that stands pat. Ignores the
+    //cards we hold, which barely moves the numbers.
+    #[default]
+    RandomHand,
+    //A hand of exactly this rank (eg. the pair of jacks needed to open).
+    Fixed(HandRank),
+}
+
+///Exact odds for one way of discarding from a five card hand.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscardOption {
+    discards: Vec<usize>,
+    draws: u64,
+    category_counts: [u64; CATEGORIES],
+    win_rate: f64,
+}
+
+impl DiscardOption {
+    //Indexes into the hand, same as DrawGame::draw takes.
+    pub fn get_discards(&self) -> &Vec<usize> {
+        &self.discards
+    }
+    //How many different sets of replacement cards there are.
+    pub fn get_draws(&self) -> u64 {
+        self.draws
+    }
+    //Chance of beating the opponent, ties counting half, 0 to 1.
+    pub fn get_win_rate(&self) -> f64 {
+        self.win_rate
+    }
+
+    //Chance of finishing with the given HandType, 0 to 1.
+    pub fn probability(&self, hand_type: HandType) -> f64 {
+        self.probabilities()
+            .into_iter()
+            .find(|(t, _)| *t == hand_type)
+            .map_or(0.0, |(_, chance)| chance)
+    }
+
+    //Every HandType the draw can finish with, best first, with its chance.
+    pub fn probabilities(&self) -> Vec<(HandType, f64)> {
+        (1..CATEGORIES)
+            .rev()
+            .filter(|&category| self.category_counts[category] > 0)
+            .map(|category| {
+                (
+                    HandRank::new(category as u8, &[]).hand_type(),
+                    self.category_counts[category] as f64 / self.draws as f64,
+                )
+            })
+            .collect()
+    }
+}
+
+//Odds for all 32 ways of discarding (standing pat included), best win rate first.
+pub fn discard_options(hand: &Hand, opponent: Opponent) -> Result<Vec<DiscardOption>, CardError> {
+    let mut options = Vec::new();
+    for mask in 0..1usize << HAND_SIZE {
+        let discards: Vec<usize> = (0..HAND_SIZE).filter(|i| mask & (1 << i) != 0).collect();
+        options.push(discard_option(hand, &discards, opponent)?);
+    }
+    // Stable, so equal win rates keep the fewer-discards-first order.
+    options.sort_by(|a, b| b.win_rate.total_cmp(&a.win_rate));
+    Ok(options)
+}
+
+//Odds for one set of discards, by trying every draw from the 47 unseen cards.
+pub fn discard_option(
+    hand: &Hand,
+    discards: &[usize],
+    opponent: Opponent,
+) -> Result<DiscardOption, CardError> {
+    let cards = hand.get_hand();
+    if cards.len() != HAND_SIZE {
+        return Err(CardError::CardCount {
+            count: cards.len(),
+            min: HAND_SIZE,
+            max: HAND_SIZE,
+        });
+    }
+    if let Some(&joker) = cards.iter().find(|c| c.is_joker()) {
+        return Err(CardError::WildCard(joker));
+    }
+    let mut discards = discards.to_vec();
+    discards.sort();
+    discards.dedup();
+    if let Some(&index) = discards.iter().find(|&&i| i >= HAND_SIZE) {
+        return Err(CardError::IndexOutOfRange {
+            index,
+            len: HAND_SIZE,
+        });
+    }
+    let kept: Vec<PlayingCard> = (0..HAND_SIZE)
+        .filter(|i| !discards.contains(i))
+        .map(|i| cards[i])
+        .collect();
+    let unseen = CardSet::from(hand).complement().to_vec();
+
+    let mut category_counts = [0u64; CATEGORIES];
+    let mut wins = 0.0;
+    let mut draws = 0;
+    let mut five = [cards[0]; HAND_SIZE];
+    five[..kept.len()].copy_from_slice(&kept);
+    for_each_combination(unseen.len(), discards.len(), |drawn| {
+        for (slot, &i) in drawn.iter().enumerate() {
+            five[kept.len() + slot] = unseen[i];
+        }
+        let rank = lookup::evaluate(&five);
+        category_counts[rank.category() as usize] += 1;
+        wins += win_chance(rank, opponent);
+        draws += 1;
+    });
+    Ok(DiscardOption {
+        discards,
+        draws,
+        category_counts,
+        win_rate: wins / draws as f64,
+    })
+}
+
+// Chance a finished hand beats the opponent, ties counting half.
+fn win_chance(rank: HandRank, opponent: Opponent) -> f64 {
+    match opponent {
+        Opponent::Fixed(theirs) if rank > theirs => 1.0,
+        Opponent::Fixed(theirs) if rank == theirs => 0.5,
+        Opponent::Fixed(_) => 0.0,
+        Opponent::RandomHand => {
+            let table = RANDOM_HANDS.get_or_init(build_random_hands);
+            let index = table
+                .binary_search_by_key(&rank, |&(r, _, _)| r)
+                .expect("Every five card rank is in the table.");
+            let (_, below, same) = table[index];
+            let (_, last_below, last_same) = table[table.len() - 1];
+            let total = last_below + last_same;
+            (below as f64 + same as f64 / 2.0) / total as f64
+        }
+    }
+}
+
+// Ranks every five card hand once and counts them up.
+fn build_random_hands() -> Vec<(HandRank, u64, u64)> {
+    let deck = CardSet::full_deck().to_vec();
+    let mut counts: HashMap<HandRank, u64> = HashMap::new();
+    for_each_combination(deck.len(), HAND_SIZE, |combo| {
+        let five = [
+            deck[combo[0]],
+            deck[combo[1]],
+            deck[combo[2]],
+            deck[combo[3]],
+            deck[combo[4]],
+        ];
+        *counts.entry(lookup::evaluate(&five)).or_insert(0) += 1;
+    });
+    let mut ranks: Vec<(HandRank, u64)> = counts.into_iter().collect();
+    ranks.sort();
+    let mut below = 0;
+    ranks
+        .into_iter()
+        .map(|(rank, count)| {
+            let entry = (rank, below, count);
+            below += count;
+            entry
+        })
+        .collect()
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hand::card::Suit;
+
+    #[test]
+    fn single_option_test() {
+        // Four to a flush, throwing the club: 9 hearts left out of 47.
+        let hand: Hand = "Ah Kh 7h 4h 2c".parse().unwrap();
+        let club = hand.get_hand().iter().position(|c| c.numeric_value() == 2);
+        let option = discard_option(&hand, &[club.unwrap()], Opponent::RandomHand).unwrap();
+        assert_eq!(option.get_draws(), 47);
+        assert!((option.probability(HandType::Flush) - 9.0 / 47.0).abs() < 1e-12);
+        let total: f64 = option.probabilities().iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+
+        // Standing pat on a made hand against a fixed hand is all or nothing.
+        let flush: Hand = "Ah Kh 7h 4h 2h".parse().unwrap();
+        let pair_of_jacks = "Jh Jd 5c 4s 2d".parse::<Hand>().unwrap().rank();
+        let pat = discard_option(&flush, &[], Opponent::Fixed(pair_of_jacks)).unwrap();
+        assert_eq!(pat.get_draws(), 1);
+        assert_eq!(pat.get_win_rate(), 1.0);
+        assert_eq!(pat.probability(HandType::Flush), 1.0);
+
+        assert_eq!(
+            discard_option(&hand, &[5], Opponent::RandomHand),
+            Err(CardError::IndexOutOfRange { index: 5, len: 5 })
+        );
+        let short: Hand = "Ah Kh".parse().unwrap();
+        assert_eq!(
+            discard_option(&short, &[], Opponent::RandomHand),
+            Err(CardError::CardCount {
+                count: 2,
+                min: 5,
+                max: 5
+            })
+        );
+        let joker = PlayingCard::joker(Suit::Spade);
+        let mut cards = hand.get_hand().clone();
+        cards[0] = joker;
+        assert_eq!(
+            discard_option(&Hand::from(cards).unwrap(), &[], Opponent::RandomHand),
+            Err(CardError::WildCard(joker))
+        );
+    }
+
+    #[test]
+    fn all_options_test() {
+        let hand: Hand = "Qs Qd 9c 6h 2s".parse().unwrap();
+        let options = discard_options(&hand, Opponent::RandomHand).unwrap();
+        assert_eq!(options.len(), 32);
+        let draws: u64 = options.iter().map(|o| o.get_draws()).sum();
+        // Every five card hand, counted once over all the discard sets.
+        assert_eq!(draws, 2_598_960);
+        // Keeping the queens and drawing three is the textbook play.
+        let queens: Vec<usize> = (0..5)
+            .filter(|&i| hand.get_hand()[i].numeric_value() != 12)
+            .collect();
+        assert_eq!(options[0].get_discards(), &queens);
+        assert!(
+            options
+                .windows(2)
+                .all(|w| w[0].get_win_rate() >= w[1].get_win_rate())
+        );
+    }
+}
@@ -0,0 +1,413 @@
+use rand::SeedableRng;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+use super::{DrawGame, DrawPhase};
+use crate::game::betting::{Action, Betting, LegalAction};
+use crate::hand::Hand;
+use crate::hand::card::PlayingCard;
+use crate::hand::evaluator::best_wild_hand;
+use crate::hand::hand_type::HandType;
+use crate::hand::rank::HandRank;
+use crate::hand::wild::WildCards;
+
+// CONSTANTS
+pub const DEFAULT_SIMULATIONS: usize = 200;
+const HAND_SIZE: usize = 5;
+const JACK_VALUE: u8 = 11;
+// Heads up win chance wanted before opening the betting, and before raising a bet.
+const BET_STRENGTH: f64 = 0.7;
+const RAISE_STRENGTH: f64 = 0.85;
+
+///How a computer seat plays five card draw: what it throws and how it bets.
+pub trait Strategy {
+    //Indexes of the cards the seat throws, at most the game's discard limit.
+    fn discards(&mut self, game: &DrawGame, seat: usize) -> Vec<usize>;
+
+    //Chance the seat's hand beats one opponent, 0 to 1. Before the draw it counts
+    //what the hand can draw to.
+    fn strength(&mut self, game: &DrawGame, seat: usize) -> f64;
+
+    //Betting for the seat whose turn it is. The heads up strength is scaled down
+    //for every extra opponent still in, then handed to strength_action.
+    fn action(&mut self, game: &DrawGame, seat: usize) -> Action {
+        let betting = game.get_betting();
+        let opponents = betting.players_in_hand().len().saturating_sub(1).max(1);
+        let strength = self.strength(game, seat).powi(opponents as i32);
+        strength_action(strength, betting, seat)
+    }
+}
+
+//Bets half the pot or raises with a strong hand, calls when the hand wins more
+//often than the share of the pot it's paying, otherwise checks or folds.
+pub fn strength_action(strength: f64, betting: &Betting, seat: usize) -> Action {
+    let to_call = betting.amount_to_call(seat);
+    let pot = betting.get_pot().total();
+    for legal in betting.legal_actions() {
+        match legal {
+            LegalAction::Bet { min, max } if strength >= BET_STRENGTH => {
+                return Action::Bet((pot / 2).clamp(min, max));
+            }
+            LegalAction::Raise { min, max } if strength >= RAISE_STRENGTH => {
+                let target = betting.get_current_bet() + (pot + to_call) / 2;
+                return Action::Raise(target.clamp(min, max));
+            }
+            _ => (),
+        }
+    }
+    if to_call == 0 {
+        return Action::Check;
+    }
+    let price = to_call as f64 / (pot + to_call) as f64;
+    if strength >= price {
+        Action::Call
+    } else {
+        Action::Fold
+    }
+}
+
+///Standard play: stand pat on a straight or better, keep two pair, trips and
+///jacks or better, otherwise draw to four flushes and open ended straights
+///ahead of a small pair. Bets off a fixed table of hand strengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StandardStrategy;
+
+impl Strategy for StandardStrategy {
+    fn discards(&mut self, game: &DrawGame, seat: usize) -> Vec<usize> {
+        standard_discards(
+            &game.get_hands()[seat],
+            game.get_max_discard(),
+            game.get_wild_cards(),
+        )
+    }
+
+    fn strength(&mut self, game: &DrawGame, seat: usize) -> f64 {
+        let hand = &game.get_hands()[seat];
+        let wild = game.get_wild_cards();
+        let Ok(played) = best_wild_hand(hand.get_hand(), wild) else {
+            return 0.0;
+        };
+        let made = match played.get_hand_type() {
+            HandType::FiveKind | HandType::RoyalFlush | HandType::StraightFlush => 1.0,
+            HandType::FourKind => 0.98,
+            HandType::FullHouse => 0.95,
+            HandType::Flush => 0.9,
+            HandType::Straight => 0.86,
+            HandType::ThreeKind => 0.78,
+            HandType::TwoPair => 0.68,
+            HandType::Pair if played.ranked_cards()[0].numeric_value() >= JACK_VALUE => 0.55,
+            HandType::Pair => 0.42,
+            HandType::HighCard | HandType::None => 0.2,
+        };
+        let can_draw = matches!(
+            game.get_phase(),
+            DrawPhase::PreDrawBetting | DrawPhase::Drawing
+        );
+        if !can_draw || game.get_max_discard() == 0 {
+            return made;
+        }
+        let cards = hand.get_hand();
+        if four_flush(cards, wild).is_some() {
+            made.max(0.45)
+        } else if open_ended(cards, wild).is_some() {
+            made.max(0.38)
+        } else {
+            made
+        }
+    }
+}
+
+//Standard play discards (see StandardStrategy). Wild cards are never thrown.
+pub fn standard_discards(hand: &Hand, max_discard: usize, wild: &WildCards) -> Vec<usize> {
+    let cards = hand.get_hand();
+    let Ok(played) = best_wild_hand(cards, wild) else {
+        return Vec::new();
+    };
+    let keep_made = match played.get_hand_type() {
+        HandType::FiveKind
+        | HandType::RoyalFlush
+        | HandType::StraightFlush
+        | HandType::FourKind
+        | HandType::FullHouse
+        | HandType::Flush
+        | HandType::Straight => return Vec::new(),
+        HandType::ThreeKind | HandType::TwoPair => true,
+        HandType::Pair => played.ranked_cards()[0].numeric_value() >= JACK_VALUE,
+        HandType::HighCard | HandType::None => false,
+    };
+    if !keep_made && max_discard > 0 {
+        let draw = four_flush(cards, wild).or_else(|| open_ended(cards, wild));
+        if let Some(index) = draw {
+            return vec![index];
+        }
+    }
+    let mut singles: Vec<usize> = (0..cards.len())
+        .filter(|&i| !wild.is_wild(&cards[i]))
+        .filter(|&i| {
+            cards
+                .iter()
+                .filter(|c| c.get_value() == cards[i].get_value())
+                .count()
+                == 1
+        })
+        .collect();
+    // Lowest value first.
+    singles.sort_by_key(|&i| cards[i].numeric_value());
+    singles.truncate(max_discard);
+    singles
+}
+
+// The card to throw when the other four (wild cards included) share a suit.
+fn four_flush(cards: &[PlayingCard], wild: &WildCards) -> Option<usize> {
+    if cards.len() != HAND_SIZE {
+        return None;
+    }
+    cards.iter().find_map(|anchor| {
+        let off_suit: Vec<usize> = (0..cards.len())
+            .filter(|&i| !wild.is_wild(&cards[i]) && cards[i].get_suit() != anchor.get_suit())
+            .collect();
+        match off_suit[..] {
+            [index] if !wild.is_wild(anchor) => Some(index),
+            _ => None,
+        }
+    })
+}
+
+// The card to throw when the other four (wild cards included) are four in a row
+// that can be filled at either end, so not A234 or JQKA.
+fn open_ended(cards: &[PlayingCard], wild: &WildCards) -> Option<usize> {
+    if cards.len() != HAND_SIZE {
+        return None;
+    }
+    let wilds: Vec<usize> = (0..cards.len())
+        .filter(|&i| wild.is_wild(&cards[i]))
+        .collect();
+    for low in 2..=10u8 {
+        let mut kept = wilds.clone();
+        for value in low..low + 4 {
+            let natural = (0..cards.len())
+                .find(|&i| !wild.is_wild(&cards[i]) && cards[i].numeric_value() == value);
+            kept.extend(natural);
+        }
+        if kept.len() == HAND_SIZE - 1 {
+            return (0..cards.len()).find(|i| !kept.contains(i));
+        }
+    }
+    None
+}
+
+///Plays every legal draw out against sampled opponents and takes whichever wins
+///most. Each sample deals an opponent five of the cards this seat can't see and
+///lets them draw by standard play, then every option draws from the same
+///leftover cards so they're compared on equal luck. Seeded, so a game replays.
+#[derive(Debug, Clone)]
+pub struct SimulationStrategy {
+    samples: usize,
+    rng: ChaCha8Rng,
+}
+
+impl SimulationStrategy {
+    pub fn new(samples: usize, seed: u64) -> SimulationStrategy {
+        SimulationStrategy {
+            samples: samples.max(1),
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+    //Standard getter.
+    pub fn get_samples(&self) -> usize {
+        self.samples
+    }
+
+    // Heads up win rate for each set of discards, ties counting half.
+    fn simulate(&mut self, game: &DrawGame, seat: usize, options: &[Vec<usize>]) -> Vec<f64> {
+        let cards = game.get_hands()[seat].get_hand();
+        let wild = game.get_wild_cards();
+        let max_discard = game.get_max_discard();
+        let mut unseen = unseen_cards(game, seat);
+        let needed = HAND_SIZE + 2 * max_discard;
+        if cards.len() != HAND_SIZE || unseen.len() < needed {
+            return vec![0.0; options.len()];
+        }
+        let mut wins = vec![0.0; options.len()];
+        for _i in 0..self.samples {
+            let (dealt, _) = unseen.partial_shuffle(&mut self.rng, needed);
+            let opponent =
+                Hand::from(dealt[..HAND_SIZE].to_vec()).expect("Unseen cards are all different.");
+            let thrown = standard_discards(&opponent, max_discard, wild);
+            let mut theirs: Vec<PlayingCard> = (0..HAND_SIZE)
+                .filter(|i| !thrown.contains(i))
+                .map(|i| opponent.get_hand()[i])
+                .collect();
+            theirs.extend_from_slice(&dealt[HAND_SIZE..HAND_SIZE + thrown.len()]);
+            let their_rank = played_rank(&theirs, wild);
+
+            let replacements = &dealt[HAND_SIZE + max_discard..];
+            for (option, win) in options.iter().zip(wins.iter_mut()) {
+                let mut ours: Vec<PlayingCard> = (0..HAND_SIZE)
+                    .filter(|i| !option.contains(i))
+                    .map(|i| cards[i])
+                    .collect();
+                ours.extend_from_slice(&replacements[..option.len()]);
+                let our_rank = played_rank(&ours, wild);
+                *win += if our_rank > their_rank {
+                    1.0
+                } else if our_rank == their_rank {
+                    0.5
+                } else {
+                    0.0
+                };
+            }
+        }
+        wins.iter().map(|w| w / self.samples as f64).collect()
+    }
+
+    // Every legal set of discards, fewest cards first so standing pat wins ties.
+    fn options(game: &DrawGame) -> Vec<Vec<usize>> {
+        let mut options: Vec<Vec<usize>> = (0..1usize << HAND_SIZE)
+            .map(|mask| (0..HAND_SIZE).filter(|i| mask & (1 << i) != 0).collect())
+            .filter(|discards: &Vec<usize>| discards.len() <= game.get_max_discard())
+            .collect();
+        options.sort_by_key(|discards| discards.len());
+        options
+    }
+}
+
+impl Strategy for SimulationStrategy {
+    fn discards(&mut self, game: &DrawGame, seat: usize) -> Vec<usize> {
+        let options = SimulationStrategy::options(game);
+        let rates = self.simulate(game, seat, &options);
+        // max_by keeps the last of equal rates, so walk backwards to keep the first.
+        let best = (0..options.len())
+            .rev()
+            .max_by(|&a, &b| rates[a].total_cmp(&rates[b]))
+            .unwrap_or(0);
+        options[best].clone()
+    }
+
+    fn strength(&mut self, game: &DrawGame, seat: usize) -> f64 {
+        let options = match game.get_phase() {
+            DrawPhase::PreDrawBetting | DrawPhase::Drawing => SimulationStrategy::options(game),
+            _ => vec![Vec::new()],
+        };
+        self.simulate(game, seat, &options)
+            .into_iter()
+            .fold(0.0, f64::max)
+    }
+}
+
+// Cards the seat hasn't seen: what's left in the deck, the other seats' hands
+// and the muck.
+fn unseen_cards(game: &DrawGame, seat: usize) -> Vec<PlayingCard> {
+    let mut unseen = game.get_deck().get_cards();
+    for (other, hand) in game.get_hands().iter().enumerate() {
+        if other != seat {
+            unseen.extend(hand.get_hand());
+        }
+    }
+    unseen.extend(game.get_muck());
+    unseen
+}
+
+fn played_rank(cards: &[PlayingCard], wild: &WildCards) -> HandRank {
+    best_wild_hand(cards, wild)
+        .expect("Draw hands hold five cards.")
+        .rank()
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::deck::Deck;
+    use crate::game::betting::Stakes;
+    use crate::hand::card::Value;
+    use crate::player::Player;
+
+    fn thrown(hand: &str, wild: &WildCards) -> Vec<String> {
+        let hand: Hand = hand.parse().unwrap();
+        standard_discards(&hand, 3, wild)
+            .iter()
+            .map(|&i| hand.get_hand()[i].to_string())
+            .collect()
+    }
+
+    fn card(card: &str) -> String {
+        card.parse::<PlayingCard>().unwrap().to_string()
+    }
+
+    #[test]
+    fn standard_discards_test() {
+        let plain = WildCards::new();
+        // Four flush and open ended straight draws throw the odd card.
+        assert_eq!(thrown("Ah Kh 7h 4h 2c", &plain), vec![card("2c")]);
+        assert_eq!(thrown("9c 8d 7h 6s 2c", &plain), vec![card("2c")]);
+        // The flush draw beats a small pair, jacks or better stay.
+        assert_eq!(thrown("5h 5d 7h 4h Kh", &plain), vec![card("5d")]);
+        assert_eq!(thrown("Jh Jd 7h 4h 2h", &plain).len(), 3);
+        // Gutshots and one way straights aren't worth it, keep the two highest.
+        assert_eq!(thrown("9c 8d 6h 5s Kc", &plain).len(), 3);
+        assert_eq!(thrown("Jc Qd Kh As 4c", &plain).len(), 3);
+        assert!(thrown("9c Td Jh Qs Kc", &plain).is_empty());
+        // The joker fills in the flush draw.
+        assert_eq!(thrown("JKs 9h 8h 3h 2c", &plain), vec![card("2c")]);
+        // Deuces wild: deuce and three kings is quads, nothing goes.
+        assert!(thrown("2c Kh Kd Ks 4c", &WildCards::deuces()).is_empty());
+    }
+
+    #[test]
+    fn strength_action_test() {
+        // Three handed, blinds 1/2, seat 2 first to act facing the big blind.
+        let mut betting = Betting::new(vec![100, 100, 100], 2);
+        betting.start_round(2);
+        betting.post_blind(0, 1);
+        betting.post_blind(1, 2);
+        // Paying 2 to win 5 needs 40%.
+        assert_eq!(strength_action(0.2, &betting, 2), Action::Fold);
+        assert_eq!(strength_action(0.5, &betting, 2), Action::Call);
+        assert_eq!(strength_action(0.9, &betting, 2), Action::Raise(4));
+
+        betting.apply(2, Action::Call).unwrap();
+        betting.apply(0, Action::Call).unwrap();
+        // Big blind can check, and opens the betting at half the pot next street.
+        assert_eq!(strength_action(0.0, &betting, 1), Action::Check);
+        betting.apply(1, Action::Check).unwrap();
+        betting.start_round(0);
+        assert_eq!(strength_action(0.75, &betting, 0), Action::Bet(3));
+        assert_eq!(strength_action(0.6, &betting, 0), Action::Check);
+    }
+
+    fn rigged_game(cards: &str) -> DrawGame {
+        let players = Player::computers(&[100, 100]);
+        let mut game =
+            DrawGame::new(players, Deck::rigged(cards), 3, Stakes::blinds(1, 2)).unwrap();
+        game.deal().unwrap();
+        game
+    }
+
+    #[test]
+    fn simulation_test() {
+        // Seat 0 gets trip kings, seat 1 a made straight.
+        let game = rigged_game("Kh 9c Kd Tc Ks Jd 7c Qh 2d 8s");
+        let mut simulation = SimulationStrategy::new(1000, 7);
+        let discards = simulation.discards(&game, 0);
+        let hand = game.get_hands()[0].get_hand();
+        assert!(!discards.is_empty());
+        assert!(discards.iter().all(|&i| hand[i].get_value() != Value::King));
+        assert!(simulation.discards(&game, 1).is_empty());
+        assert!(simulation.strength(&game, 1) > simulation.strength(&game, 0));
+        assert!(simulation.strength(&game, 1) > 0.8);
+
+        // Same seed, same decisions.
+        let mut first = SimulationStrategy::new(50, 3);
+        let mut second = SimulationStrategy::new(50, 3);
+        assert_eq!(first.strength(&game, 0), second.strength(&game, 0));
+
+        // Standard play rates the straight over the trips and bets it.
+        let mut standard = StandardStrategy;
+        assert!(standard.strength(&game, 1) > standard.strength(&game, 0));
+        assert_eq!(standard.discards(&game, 0).len(), 2);
+        let seat = game.to_act().unwrap();
+        let action = standard.action(&game, seat);
+        assert!(game.clone().act(seat, action).is_ok());
+    }
+}
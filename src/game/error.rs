@@ -0,0 +1,71 @@
+use core::fmt;
+
+use crate::game::betting::BettingError;
+use crate::hand::error::CardError;
+
+///Reasons a game can refuse to start or refuse a move.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    SeatCount {
+        seats: usize,
+        min: usize,
+        max: usize,
+    },
+    TooManyDiscards {
+        requested: usize,
+        max: usize,
+    },
+    NotEnoughCards {
+        needed: usize,
+    },
+    HoleCardCount(usize),
+    SeatOutOfRange(usize),
+    SeatNotInHand(usize),
+    AlreadyDrawn(usize),
+    WrongPhase,
+    Card(CardError),
+    Betting(BettingError),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameError::SeatCount { seats, min, max } => write!(
+                f,
+                "{} seats is not allowed, needs between {} and {}",
+                seats, min, max
+            ),
+            GameError::TooManyDiscards { requested, max } => write!(
+                f,
+                "Tried to discard {} cards, at most {} allowed",
+                requested, max
+            ),
+            GameError::NotEnoughCards { needed } => {
+                write!(f, "Game would need {} cards from a 52 card deck", needed)
+            }
+            GameError::HoleCardCount(count) => {
+                write!(f, "Can't deal {} hole cards in this game", count)
+            }
+            GameError::SeatOutOfRange(seat) => write!(f, "There is no seat {}", seat),
+            GameError::SeatNotInHand(seat) => write!(f, "Seat {} is not in the hand", seat),
+            GameError::AlreadyDrawn(seat) => write!(f, "Seat {} has already drawn", seat),
+            GameError::WrongPhase => write!(f, "Can't do that at this point of the hand"),
+            GameError::Card(err) => write!(f, "{}", err),
+            GameError::Betting(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+impl From<CardError> for GameError {
+    fn from(err: CardError) -> GameError {
+        GameError::Card(err)
+    }
+}
+
+impl From<BettingError> for GameError {
+    fn from(err: BettingError) -> GameError {
+        GameError::Betting(err)
+    }
+}
@@ -0,0 +1,535 @@
+use crate::deck::Deck;
+use crate::game::betting::{Action, Betting, LegalAction, Stakes};
+use crate::game::error::GameError;
+use crate::game::pot::OddChipRule;
+use crate::game::seats::Seats;
+use crate::game::showdown::{ShowdownEntry, showdown};
+use crate::hand::Hand;
+use crate::hand::error::CardError;
+use crate::hand::evaluator::{best_hand, best_omaha_hand, best_omaha_low};
+use crate::hand::low::{LowRank, LowballRule};
+use crate::player::Player;
+
+// CONSTANTS
+pub const MIN_SEATS: usize = 2;
+pub const MAX_SEATS: usize = 10;
+const HOLDEM_HOLE_CARDS: usize = 2;
+const BOARD_SIZE: usize = 5;
+const BURN_CARDS: usize = 3;
+
+///Which flop game is being dealt. Omaha hands have to use exactly two hole cards
+///and three from the board, Hi-Lo also pays the best eight-or-better low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Variant {
+    #[default]
+    Holdem,
+    Omaha {
+        hole_cards: usize,
+    },
+    OmahaHiLo {
+        hole_cards: usize,
+    },
+}
+
+impl Variant {
+    pub fn hole_cards(&self) -> usize {
+        match self {
+            Variant::Holdem => HOLDEM_HOLE_CARDS,
+            Variant::Omaha { hole_cards } | Variant::OmahaHiLo { hole_cards } => *hole_cards,
+        }
+    }
+    pub fn is_hi_lo(&self) -> bool {
+        matches!(self, Variant::OmahaHiLo { .. })
+    }
+}
+
+///Where a Hold'em hand is up to. Each street is its own betting round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HoldemPhase {
+    Dealing,
+    PreFlop,
+    Flop,
+    Turn,
+    River,
+    Showdown,
+    Complete,
+}
+
+///Texas Hold'em (and Omaha, see Variant): blinds, hole cards, then the flop, turn
+///and river (each after a burn card) with a betting round on every street. Seats
+///still in at the end play the best five of their hole cards and the board.
+#[derive(Clone, Debug)]
+pub struct HoldemGame {
+    seats: Seats,
+    variant: Variant,
+    hole_cards: Vec<Hand>,
+    board: Hand,
+    deck: Deck,
+    phase: HoldemPhase,
+}
+
+impl HoldemGame {
+    //Seats are in dealing order, the button starts on the last seat. Refuses seat
+    //counts outside 2-10. Stacks come from the players.
+    pub fn new(players: Vec<Player>, deck: Deck, stakes: Stakes) -> Result<HoldemGame, GameError> {
+        HoldemGame::with_variant(players, deck, stakes, Variant::Holdem)
+    }
+
+    //Same as new for any variant. Omaha takes 4-6 hole cards, and refuses seat
+    //counts that would run the deck out. No wild cards in flop games, so a deck
+    //with jokers is refused too.
+    pub fn with_variant(
+        players: Vec<Player>,
+        deck: Deck,
+        stakes: Stakes,
+        variant: Variant,
+    ) -> Result<HoldemGame, GameError> {
+        let seats = Seats::new(players, stakes, MIN_SEATS, MAX_SEATS)?;
+        if variant != Variant::Holdem && !(4..=6).contains(&variant.hole_cards()) {
+            return Err(GameError::HoleCardCount(variant.hole_cards()));
+        }
+        if let Some(joker) = deck.find_joker() {
+            return Err(CardError::WildCard(joker).into());
+        }
+        let needed = seats.count() * variant.hole_cards() + BOARD_SIZE + BURN_CARDS;
+        if needed > deck.remaining() {
+            return Err(GameError::NotEnoughCards { needed });
+        }
+        Ok(HoldemGame {
+            variant,
+            hole_cards: vec![Hand::new(variant.hole_cards()); seats.count()],
+            board: Hand::new(BOARD_SIZE),
+            deck,
+            phase: HoldemPhase::Dealing,
+            seats,
+        })
+    }
+
+    // -------------------------
+    // Getters
+    // -------------------------
+
+    //Just a getter.
+    pub fn get_players(&self) -> &Vec<Player> {
+        self.seats.get_players()
+    }
+    //Standard getter.
+    pub fn get_variant(&self) -> Variant {
+        self.variant
+    }
+    //Just a getter.
+    pub fn get_hole_cards(&self) -> &Vec<Hand> {
+        &self.hole_cards
+    }
+    //Just a getter.
+    pub fn get_board(&self) -> &Hand {
+        &self.board
+    }
+    //Just a getter.
+    pub fn get_deck(&self) -> &Deck {
+        &self.deck
+    }
+    //Just a getter.
+    pub fn get_betting(&self) -> &Betting {
+        self.seats.get_betting()
+    }
+    //Standard getter.
+    pub fn get_phase(&self) -> HoldemPhase {
+        self.phase
+    }
+    //Standard getter.
+    pub fn get_button(&self) -> usize {
+        self.seats.get_button()
+    }
+    //Seat whose turn it is to bet, if a betting round is running.
+    pub fn to_act(&self) -> Option<usize> {
+        match self.phase {
+            HoldemPhase::PreFlop | HoldemPhase::Flop | HoldemPhase::Turn | HoldemPhase::River => {
+                self.seats.to_act()
+            }
+            _ => None,
+        }
+    }
+
+    //What the seat to act may do, empty when nobody is betting.
+    pub fn legal_actions(&self) -> Vec<LegalAction> {
+        match self.to_act() {
+            Some(_) => self.seats.legal_actions(),
+            None => Vec::new(),
+        }
+    }
+
+    //See Seats::set_button. Only allowed before the deal.
+    pub fn set_button(&mut self, seat: usize) -> Result<(), GameError> {
+        if self.phase != HoldemPhase::Dealing {
+            return Err(GameError::WrongPhase);
+        }
+        self.seats.set_button(seat)
+    }
+
+    //See Seats::set_blinds. Only allowed before the deal.
+    pub fn set_blinds(&mut self, small: Option<usize>, big: usize) -> Result<(), GameError> {
+        if self.phase != HoldemPhase::Dealing {
+            return Err(GameError::WrongPhase);
+        }
+        self.seats.set_blinds(small, big)
+    }
+
+    //See Seats::set_odd_chip_rule.
+    pub fn set_odd_chip_rule(&mut self, rule: OddChipRule) {
+        self.seats.set_odd_chip_rule(rule);
+    }
+
+    // -------------------------
+    // Game Flow
+    // -------------------------
+
+    //Posts antes and blinds, deals two hole cards to every seat one card at a time
+    //starting left of the button, and opens the pre-flop betting.
+    pub fn deal(&mut self) -> Result<(), GameError> {
+        if self.phase != HoldemPhase::Dealing {
+            return Err(GameError::WrongPhase);
+        }
+        for _i in 0..self.variant.hole_cards() {
+            for seat in self.seats.deal_order() {
+                self.deck.deal_to(&mut self.hole_cards[seat])?;
+            }
+        }
+        self.check_conservation();
+
+        self.seats.post_blinds();
+        self.phase = HoldemPhase::PreFlop;
+        self.advance()
+    }
+
+    //Betting action for the seat whose turn it is.
+    pub fn act(&mut self, seat: usize, action: Action) -> Result<u32, GameError> {
+        if self.to_act().is_none() {
+            return Err(GameError::WrongPhase);
+        }
+        let paid = self.seats.act(seat, action)?;
+        self.advance()?;
+        Ok(paid)
+    }
+
+    //Seats still in the hand, starting left of the button.
+    pub fn seats_in_hand(&self) -> Vec<usize> {
+        self.seats.seats_in_hand()
+    }
+
+    //Best five cards out of a seat's hole cards and the board, once there are
+    //at least three board cards. Omaha uses exactly two hole cards.
+    pub fn best_hand(&self, seat: usize) -> Option<Hand> {
+        self.evaluate(seat).ok()
+    }
+
+    //Best eight-or-better low for a seat in Omaha Hi-Lo. None in the other variants
+    //or when the seat has no low.
+    pub fn best_low(&self, seat: usize) -> Option<LowRank> {
+        if !self.variant.is_hi_lo() {
+            return None;
+        }
+        let hole = self.hole_cards.get(seat)?.get_hand();
+        best_omaha_low(hole, self.board.get_hand(), LowballRule::EightOrBetter)
+            .ok()
+            .flatten()
+            .map(|(low, _)| low)
+    }
+
+    //Finishing groups among the seats still in, best hand first, ties grouped together.
+    pub fn showdown(&self) -> Vec<Vec<ShowdownEntry>> {
+        let best: Vec<(usize, Hand)> = self
+            .seats_in_hand()
+            .into_iter()
+            .filter_map(|seat| self.best_hand(seat).map(|hand| (seat, hand)))
+            .collect();
+        let hands: Vec<(usize, &Hand)> = best.iter().map(|(seat, hand)| (*seat, hand)).collect();
+        showdown(&hands)
+    }
+
+    //Pays out the best hands of the seats still in, see Seats::settle. Hi-Lo
+    //splits each pot with the best low.
+    pub fn settle(&mut self) -> Result<Vec<(usize, u32)>, GameError> {
+        if self.phase != HoldemPhase::Showdown {
+            return Err(GameError::WrongPhase);
+        }
+        let in_hand = self.seats_in_hand();
+        let best: Vec<(usize, Hand)> = if in_hand.len() == 1 {
+            vec![(in_hand[0], self.hole_cards[in_hand[0]].clone())]
+        } else {
+            in_hand
+                .iter()
+                .map(|&seat| self.evaluate(seat).map(|hand| (seat, hand)))
+                .collect::<Result<_, _>>()?
+        };
+        let hands: Vec<(usize, &Hand)> = best.iter().map(|(seat, hand)| (*seat, hand)).collect();
+        let payouts = if self.variant.is_hi_lo() && in_hand.len() > 1 {
+            let lows: Vec<(usize, LowRank)> = in_hand
+                .iter()
+                .filter_map(|&seat| self.best_low(seat).map(|low| (seat, low)))
+                .collect();
+            self.seats.settle_hi_lo(&hands, &lows)
+        } else {
+            self.seats.settle(&hands)
+        };
+        self.phase = HoldemPhase::Complete;
+        Ok(payouts)
+    }
+
+    // Moves the hand on once a betting round is finished: burns and deals the next
+    // street and opens its betting. If nobody can bet any more (everyone else
+    // folded) it goes straight to the showdown, if everyone left is all in the
+    // rest of the board is run out first.
+    fn advance(&mut self) -> Result<(), GameError> {
+        loop {
+            if self.seats.get_betting().players_in_hand().len() <= 1 {
+                self.phase = HoldemPhase::Showdown;
+                return Ok(());
+            }
+            if !self.seats.get_betting().is_round_complete() {
+                return Ok(());
+            }
+            let (next, cards) = match self.phase {
+                HoldemPhase::PreFlop => (HoldemPhase::Flop, 3),
+                HoldemPhase::Flop => (HoldemPhase::Turn, 1),
+                HoldemPhase::Turn => (HoldemPhase::River, 1),
+                HoldemPhase::River => {
+                    self.phase = HoldemPhase::Showdown;
+                    return Ok(());
+                }
+                _ => return Ok(()),
+            };
+            self.deck.burn()?;
+            for _i in 0..cards {
+                self.deck.deal_to(&mut self.board)?;
+            }
+            self.check_conservation();
+            // Turn and river play for the big bet in fixed limit.
+            self.seats
+                .start_round_left_of_button(next == HoldemPhase::Turn);
+            self.phase = next;
+        }
+    }
+
+    // Same as best_hand, but says why there isn't one.
+    fn evaluate(&self, seat: usize) -> Result<Hand, GameError> {
+        let hole = self
+            .hole_cards
+            .get(seat)
+            .ok_or(GameError::SeatOutOfRange(seat))?
+            .get_hand();
+        let board = self.board.get_hand();
+        let hand = match self.variant {
+            Variant::Holdem => {
+                let mut cards = hole.clone();
+                cards.extend(board.iter().copied());
+                best_hand(&cards)?
+            }
+            _ => best_omaha_hand(hole, board)?,
+        };
+        Ok(hand)
+    }
+
+    fn check_conservation(&self) {
+        let mut hands: Vec<&Hand> = self.hole_cards.iter().collect();
+        hands.push(&self.board);
+        self.deck.debug_check_conservation(&hands, &[]);
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::game::betting::BettingError;
+    use crate::hand::card::PlayingCard;
+    use crate::hand::hand_type::HandType;
+
+    fn cards(s: &str) -> Vec<PlayingCard> {
+        s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn holdem_hand_test() {
+        // Hole cards go out one at a time from seat 0 (left of the button on 1), then
+        // a burn before every street.
+        let deck = Deck::rigged("Ah 7c Kh 3d 9s Qh Jh 3c 4s Th 5d 8d");
+        let mut game =
+            HoldemGame::new(Player::computers(&[100, 100]), deck, Stakes::blinds(1, 2)).unwrap();
+        assert_eq!(game.to_act(), None);
+        game.deal().unwrap();
+        assert_eq!(game.get_phase(), HoldemPhase::PreFlop);
+        assert_eq!(game.get_hole_cards()[0].get_hand().len(), 2);
+
+        // Heads up the button posts the small blind and acts first pre-flop.
+        assert_eq!(game.to_act(), Some(1));
+        game.act(1, Action::Call).unwrap();
+        game.act(0, Action::Check).unwrap();
+        assert_eq!(game.get_phase(), HoldemPhase::Flop);
+        assert_eq!(game.get_board().get_hand().len(), 3);
+        assert_eq!(game.get_deck().get_burned(), &cards("9s"));
+
+        // After the flop the big blind acts first.
+        assert_eq!(game.to_act(), Some(0));
+        game.act(0, Action::Check).unwrap();
+        game.act(1, Action::Check).unwrap();
+        assert_eq!(game.get_phase(), HoldemPhase::Turn);
+        game.act(0, Action::Bet(4)).unwrap();
+        game.act(1, Action::Call).unwrap();
+        assert_eq!(game.get_phase(), HoldemPhase::River);
+        assert_eq!(game.get_board().get_hand().len(), 5);
+        assert_eq!(game.get_deck().get_burned().len(), 3);
+        game.act(0, Action::Check).unwrap();
+        game.act(1, Action::Check).unwrap();
+
+        assert_eq!(game.get_phase(), HoldemPhase::Showdown);
+        assert_eq!(
+            game.best_hand(0).unwrap().get_hand_type(),
+            HandType::RoyalFlush
+        );
+        assert_eq!(game.best_hand(1).unwrap().get_hand_type(), HandType::Pair);
+        assert_eq!(game.settle(), Ok(vec![(0, 12)]));
+        let stacks: Vec<u32> = game.get_players().iter().map(|p| p.get_stack()).collect();
+        assert_eq!(stacks, vec![106, 94]);
+        assert_eq!(game.get_phase(), HoldemPhase::Complete);
+    }
+
+    #[test]
+    fn all_in_runs_out_board_test() {
+        let mut game = HoldemGame::new(
+            Player::computers(&[20, 100, 100]),
+            Deck::seeded(5),
+            Stakes::blinds(1, 2),
+        )
+        .unwrap();
+        game.deal().unwrap();
+        // Button on seat 2 opens, then both blinds.
+        assert_eq!(game.to_act(), Some(2));
+        game.act(2, Action::Fold).unwrap();
+        game.act(0, Action::AllIn).unwrap();
+        game.act(1, Action::Call).unwrap();
+
+        assert_eq!(game.get_phase(), HoldemPhase::Showdown);
+        assert_eq!(game.get_board().get_hand().len(), 5);
+        assert_eq!(game.showdown().iter().map(|g| g.len()).sum::<usize>(), 2);
+        game.settle().unwrap();
+        let total: u32 = game.get_players().iter().map(|p| p.get_stack()).sum();
+        assert_eq!(total, 220);
+    }
+
+    #[test]
+    fn fold_wins_test() {
+        let mut game = HoldemGame::new(
+            Player::computers(&[100, 100, 100]),
+            Deck::seeded(1),
+            Stakes::blinds(1, 2),
+        )
+        .unwrap();
+        assert_eq!(
+            HoldemGame::new(
+                Player::computers(&[100]),
+                Deck::seeded(1),
+                Stakes::default()
+            )
+            .err(),
+            Some(GameError::SeatCount {
+                seats: 1,
+                min: MIN_SEATS,
+                max: MAX_SEATS
+            })
+        );
+        let jokers = Deck::with_jokers(2).unwrap();
+        assert!(matches!(
+            HoldemGame::new(Player::computers(&[100, 100]), jokers, Stakes::default()),
+            Err(GameError::Card(CardError::WildCard(_)))
+        ));
+        game.deal().unwrap();
+        assert_eq!(
+            game.act(0, Action::Call),
+            Err(GameError::Betting(BettingError::NotYourTurn {
+                expected: 2
+            }))
+        );
+        game.act(2, Action::Raise(6)).unwrap();
+        game.act(0, Action::Fold).unwrap();
+        game.act(1, Action::Fold).unwrap();
+
+        assert_eq!(game.get_phase(), HoldemPhase::Showdown);
+        assert!(game.get_board().get_hand().is_empty());
+        assert_eq!(game.settle(), Ok(vec![(2, 9)]));
+        assert_eq!(game.settle(), Err(GameError::WrongPhase));
+    }
+
+    #[test]
+    fn dead_small_blind_test() {
+        let mut game = HoldemGame::new(
+            Player::computers(&[100, 100, 100]),
+            Deck::seeded(2),
+            Stakes::blinds(1, 2),
+        )
+        .unwrap();
+        game.set_button(0).unwrap();
+        game.set_blinds(None, 1).unwrap();
+        assert_eq!(game.set_blinds(None, 3), Err(GameError::SeatOutOfRange(3)));
+        game.deal().unwrap();
+        assert_eq!(game.get_betting().get_pot().total(), 2);
+        assert_eq!(game.to_act(), Some(2));
+        assert_eq!(game.set_blinds(None, 2), Err(GameError::WrongPhase));
+    }
+
+    #[test]
+    fn omaha_hi_lo_test() {
+        assert_eq!(
+            HoldemGame::with_variant(
+                Player::computers(&[100; 10]),
+                Deck::new(),
+                Stakes::blinds(1, 2),
+                Variant::Omaha { hole_cards: 6 }
+            )
+            .err(),
+            Some(GameError::NotEnoughCards { needed: 68 })
+        );
+        assert_eq!(
+            HoldemGame::with_variant(
+                Player::computers(&[100; 2]),
+                Deck::new(),
+                Stakes::blinds(1, 2),
+                Variant::Omaha { hole_cards: 3 }
+            )
+            .err(),
+            Some(GameError::HoleCardCount(3))
+        );
+
+        // Seat 0: Ah 2c Kd Ks, seat 1: Qh Qd Jc Js.
+        // Board: 3h 6c 8d, Qc, Tc.
+        let deck = Deck::rigged("Ah Qh 2c Qd Kd Jc Ks Js 9s 3h 6c 8d 4s Qc 5d Tc");
+        let mut game = HoldemGame::with_variant(
+            Player::computers(&[100, 100]),
+            deck,
+            Stakes::blinds(1, 2),
+            Variant::OmahaHiLo { hole_cards: 4 },
+        )
+        .unwrap();
+        game.deal().unwrap();
+        assert_eq!(game.get_hole_cards()[0].get_hand().len(), 4);
+        game.act(1, Action::Call).unwrap();
+        game.act(0, Action::Check).unwrap();
+        for _street in 0..2 {
+            game.act(0, Action::Check).unwrap();
+            game.act(1, Action::Check).unwrap();
+        }
+        game.act(0, Action::Bet(3)).unwrap();
+        game.act(1, Action::Call).unwrap();
+
+        assert_eq!(game.get_phase(), HoldemPhase::Showdown);
+        // Three clubs on board but seat 1 only holds one club: trips, not a flush.
+        assert_eq!(
+            game.best_hand(1).unwrap().get_hand_type(),
+            HandType::ThreeKind
+        );
+        assert_eq!(game.best_low(0).unwrap().to_string(), "8-6-3-2-A");
+        assert_eq!(game.best_low(1), None);
+        // Low half to seat 0, high half to seat 1.
+        assert_eq!(game.settle(), Ok(vec![(0, 5), (1, 5)]));
+    }
+}
@@ -0,0 +1,391 @@
+use crate::game::showdown::{showdown, winners};
+use crate::hand::Hand;
+use crate::hand::low::LowRank;
+
+///Chips put in over a whole hand, tracked per seat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pot {
+    contributions: Vec<u32>,
+}
+
+impl Pot {
+    pub fn new(seats: usize) -> Pot {
+        Pot {
+            contributions: vec![0; seats],
+        }
+    }
+
+    pub fn add(&mut self, seat: usize, amount: u32) {
+        self.contributions[seat] += amount;
+    }
+
+    pub fn total(&self) -> u32 {
+        self.contributions.iter().sum()
+    }
+
+    //Just a getter.
+    pub fn get_contributions(&self) -> &Vec<u32> {
+        &self.contributions
+    }
+
+    //Splits the whole pot evenly between the winners. Odd chips go out one at a
+    //time in the order the winners are given.
+    pub fn split(&self, winners: &[usize]) -> Vec<(usize, u32)> {
+        split_chips(self.total(), winners)
+    }
+
+    //Breaks the contributions into the main pot and side pots, smallest all in
+    //first. Only seats that haven't folded can win a pot, folded chips still count
+    //towards every level they reached. If no live seat put anything in, the whole
+    //pot goes to the live seats (or back to whoever paid, if everyone folded).
+    pub fn side_pots(&self, folded: &[bool]) -> Vec<SidePot> {
+        let mut levels: Vec<u32> = self
+            .contributions
+            .iter()
+            .enumerate()
+            .filter(|&(seat, &amount)| !folded[seat] && amount > 0)
+            .map(|(_, &amount)| amount)
+            .collect();
+        levels.sort();
+        levels.dedup();
+
+        let mut pots: Vec<SidePot> = Vec::new();
+        let mut previous = 0;
+        for level in levels {
+            let amount = self
+                .contributions
+                .iter()
+                .map(|&c| c.min(level) - c.min(previous))
+                .sum();
+            let eligible = (0..self.contributions.len())
+                .filter(|&seat| !folded[seat] && self.contributions[seat] >= level)
+                .collect();
+            pots.push(SidePot { amount, eligible });
+            previous = level;
+        }
+        // Folded chips above every live seat's total have nowhere else to go.
+        let leftover: u32 = self
+            .contributions
+            .iter()
+            .map(|&c| c.saturating_sub(previous))
+            .sum();
+        match pots.last_mut() {
+            Some(last) => last.amount += leftover,
+            None if leftover > 0 => {
+                let live: Vec<usize> = (0..folded.len()).filter(|&s| !folded[s]).collect();
+                let eligible = if live.is_empty() {
+                    (0..self.contributions.len())
+                        .filter(|&seat| self.contributions[seat] > 0)
+                        .collect()
+                } else {
+                    live
+                };
+                pots.push(SidePot {
+                    amount: leftover,
+                    eligible,
+                });
+            }
+            None => {}
+        }
+        pots
+    }
+}
+
+///One pot and the seats that can win it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidePot {
+    amount: u32,
+    eligible: Vec<usize>,
+}
+
+impl SidePot {
+    //Standard getter.
+    pub fn get_amount(&self) -> u32 {
+        self.amount
+    }
+    //Just a getter.
+    pub fn get_eligible(&self) -> &Vec<usize> {
+        &self.eligible
+    }
+}
+
+///Who gets the odd chips when a pot doesn't split evenly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OddChipRule {
+    //First winner clockwise from the button.
+    #[default]
+    LeftOfButton,
+    //Lowest numbered winning seat.
+    LowestSeat,
+}
+
+impl OddChipRule {
+    //Puts the winners in the order odd chips get handed out.
+    pub fn order(&self, seats: &mut [usize], button: usize, table_size: usize) {
+        match self {
+            OddChipRule::LeftOfButton => {
+                seats.sort_by_key(|&seat| (seat + table_size - button - 1) % table_size)
+            }
+            OddChipRule::LowestSeat => seats.sort(),
+        }
+    }
+}
+
+//Awards every pot to the best eligible hand(s). `hands` holds the seats still in,
+//`table_size` is the number of seats the button goes around. Returns the total
+//each seat won, in the order seats first won something.
+pub fn award_pots(
+    pots: &[SidePot],
+    hands: &[(usize, &Hand)],
+    button: usize,
+    table_size: usize,
+    rule: OddChipRule,
+) -> Vec<(usize, u32)> {
+    let mut payouts: Vec<(usize, u32)> = Vec::new();
+    for pot in pots {
+        let pot_winners = high_winners(pot, hands);
+        pay(
+            &mut payouts,
+            pot.amount,
+            pot_winners,
+            button,
+            table_size,
+            rule,
+        );
+    }
+    payouts
+}
+
+//Hi-Lo split. Each pot goes half to the best high hand and half to the best
+//qualifying low among the seats that can win it, the odd chip going high. A pot
+//with no qualifying low goes all to the high hand. `lows` only lists seats that
+//have a low.
+pub fn award_hi_lo_pots(
+    pots: &[SidePot],
+    highs: &[(usize, &Hand)],
+    lows: &[(usize, LowRank)],
+    button: usize,
+    table_size: usize,
+    rule: OddChipRule,
+) -> Vec<(usize, u32)> {
+    let mut payouts: Vec<(usize, u32)> = Vec::new();
+    for pot in pots {
+        let eligible_lows: Vec<&(usize, LowRank)> = lows
+            .iter()
+            .filter(|(seat, _)| pot.eligible.contains(seat))
+            .collect();
+        let best_low = eligible_lows.iter().map(|(_, low)| *low).max();
+        let high_share = match best_low {
+            Some(best) => {
+                let low_winners = eligible_lows
+                    .iter()
+                    .filter(|(_, low)| *low == best)
+                    .map(|(seat, _)| *seat)
+                    .collect();
+                let low_share = pot.amount / 2;
+                pay(
+                    &mut payouts,
+                    low_share,
+                    low_winners,
+                    button,
+                    table_size,
+                    rule,
+                );
+                pot.amount - low_share
+            }
+            None => pot.amount,
+        };
+        let high = high_winners(pot, highs);
+        pay(&mut payouts, high_share, high, button, table_size, rule);
+    }
+    payouts
+}
+
+// Seats that tie for the best hand among those eligible for a pot. A pot none of
+// the hands can win is uncontested and goes to the seats eligible for it, so no
+// chips get lost.
+fn high_winners(pot: &SidePot, hands: &[(usize, &Hand)]) -> Vec<usize> {
+    let contenders: Vec<(usize, &Hand)> = hands
+        .iter()
+        .filter(|(seat, _)| pot.eligible.contains(seat))
+        .copied()
+        .collect();
+    match contenders.len() {
+        0 => pot.eligible.clone(),
+        1 => vec![contenders[0].0],
+        _ => winners(&showdown(&contenders)),
+    }
+}
+
+// Splits an amount between winners and adds it to the running payouts.
+fn pay(
+    payouts: &mut Vec<(usize, u32)>,
+    amount: u32,
+    mut winners: Vec<usize>,
+    button: usize,
+    table_size: usize,
+    rule: OddChipRule,
+) {
+    rule.order(&mut winners, button, table_size);
+    for (seat, share) in split_chips(amount, &winners) {
+        match payouts.iter_mut().find(|(s, _)| *s == seat) {
+            Some(payout) => payout.1 += share,
+            None => payouts.push((seat, share)),
+        }
+    }
+}
+
+//Even split of an amount, odd chips handed out one each from the front of the list.
+pub fn split_chips(amount: u32, winners: &[usize]) -> Vec<(usize, u32)> {
+    if winners.is_empty() {
+        return Vec::new();
+    }
+    let share = amount / winners.len() as u32;
+    let odd = (amount % winners.len() as u32) as usize;
+    winners
+        .iter()
+        .enumerate()
+        .map(|(i, &seat)| (seat, share + if i < odd { 1 } else { 0 }))
+        .collect()
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn split_test() {
+        let mut pot = Pot::new(3);
+        pot.add(0, 10);
+        pot.add(1, 10);
+        pot.add(2, 5);
+        pot.add(0, 6);
+        assert_eq!(pot.total(), 31);
+        assert_eq!(pot.get_contributions(), &vec![16, 10, 5]);
+
+        assert_eq!(pot.split(&[2]), vec![(2, 31)]);
+        assert_eq!(pot.split(&[2, 0]), vec![(2, 16), (0, 15)]);
+        assert_eq!(split_chips(10, &[1, 2, 0]), vec![(1, 4), (2, 3), (0, 3)]);
+        assert!(pot.split(&[]).is_empty());
+    }
+
+    #[test]
+    fn side_pot_test() {
+        // Seat 0 all in for 20, seat 1 all in for 50, seats 2 and 3 put in 80,
+        // seat 4 folded after putting in 30.
+        let mut pot = Pot::new(5);
+        for (seat, amount) in [20, 50, 80, 80, 30].into_iter().enumerate() {
+            pot.add(seat, amount);
+        }
+        let folded = [false, false, false, false, true];
+        let pots = pot.side_pots(&folded);
+        assert_eq!(pots.len(), 3);
+        assert_eq!(pots[0].get_amount(), 100);
+        assert_eq!(pots[0].get_eligible(), &vec![0, 1, 2, 3]);
+        assert_eq!(pots[1].get_amount(), 100);
+        assert_eq!(pots[1].get_eligible(), &vec![1, 2, 3]);
+        assert_eq!(pots[2].get_amount(), 60);
+        assert_eq!(pots[2].get_eligible(), &vec![2, 3]);
+        let total: u32 = pots.iter().map(|p| p.get_amount()).sum();
+        assert_eq!(total, pot.total());
+
+        // Short stack has the best hand, seats 2 and 3 chop what's left.
+        let quads: Hand = "9c 9d 9h 9s 2c".parse().unwrap();
+        let two_pair: Hand = "Kc Kd 5h 5s 3c".parse().unwrap();
+        let broadway: Hand = "As Kh Qd Jc Ts".parse().unwrap();
+        let same_broadway: Hand = "Ac Kd Qh Js Th".parse().unwrap();
+        let hands = [
+            (0, &quads),
+            (1, &two_pair),
+            (2, &broadway),
+            (3, &same_broadway),
+        ];
+        let payouts = award_pots(&pots, &hands, 4, 5, OddChipRule::LeftOfButton);
+        assert_eq!(payouts, vec![(0, 100), (2, 80), (3, 80)]);
+
+        // Side pots nobody showed down for still go to the seats in them.
+        let payouts = award_pots(&pots, &hands[..1], 4, 5, OddChipRule::LowestSeat);
+        assert_eq!(payouts, vec![(0, 100), (1, 34), (2, 63), (3, 63)]);
+    }
+
+    #[test]
+    fn uncontested_pot_test() {
+        // Only folded seats put chips in, the live seat gets them all.
+        let mut pot = Pot::new(3);
+        pot.add(0, 10);
+        pot.add(1, 5);
+        let pots = pot.side_pots(&[true, true, false]);
+        assert_eq!(pots.len(), 1);
+        assert_eq!(pots[0].get_amount(), 15);
+        assert_eq!(pots[0].get_eligible(), &vec![2]);
+        let pair: Hand = "Kc Kd 7s 4d 2c".parse().unwrap();
+        let payouts = award_pots(&pots, &[(2, &pair)], 0, 3, OddChipRule::LeftOfButton);
+        assert_eq!(payouts, vec![(2, 15)]);
+
+        // Everyone folded, the chips go back to whoever paid them.
+        let pots = pot.side_pots(&[true; 3]);
+        assert_eq!(pots[0].get_eligible(), &vec![0, 1]);
+        let payouts = award_pots(&pots, &[], 0, 3, OddChipRule::LowestSeat);
+        let total: u32 = payouts.iter().map(|(_, chips)| chips).sum();
+        assert_eq!(total, 15);
+        assert!(Pot::new(3).side_pots(&[false; 3]).is_empty());
+    }
+
+    #[test]
+    fn odd_chip_test() {
+        let mut pot = Pot::new(4);
+        for seat in 0..3 {
+            pot.add(seat, 5);
+        }
+        pot.add(3, 2);
+        let folded = [false, false, false, true];
+        let pots = pot.side_pots(&folded);
+        assert_eq!(pots.len(), 1);
+        assert_eq!(pots[0].get_amount(), 17);
+
+        let broadway: Hand = "As Kh Qd Jc Ts".parse().unwrap();
+        let same_broadway: Hand = "Ac Kd Qh Js Th".parse().unwrap();
+        let eights: Hand = "8c 8d 4h 3s 2d".parse().unwrap();
+        let hands = [(0, &broadway), (1, &eights), (2, &same_broadway)];
+
+        // Button on seat 1, so seat 2 is closest on the left.
+        let payouts = award_pots(&pots, &hands, 1, 4, OddChipRule::LeftOfButton);
+        assert_eq!(payouts, vec![(2, 9), (0, 8)]);
+        let payouts = award_pots(&pots, &hands, 1, 4, OddChipRule::LowestSeat);
+        assert_eq!(payouts, vec![(0, 9), (2, 8)]);
+    }
+
+    #[test]
+    fn hi_lo_test() {
+        let mut pot = Pot::new(3);
+        for seat in 0..3 {
+            pot.add(seat, 15);
+        }
+        let pots = pot.side_pots(&[false; 3]);
+        let flush: Hand = "Ah Kh 9h 5h 2h".parse().unwrap();
+        let straight: Hand = "9c 8d 7h 6s 5c".parse().unwrap();
+        let pair: Hand = "Kc Kd 7s 4d 2c".parse().unwrap();
+        let highs = [(0, &flush), (1, &straight), (2, &pair)];
+
+        // Seat 0 scoops nothing low, seat 2 has the best low.
+        let lows = [
+            (1, LowRank::new(&[8, 7, 6, 5, 1])),
+            (2, LowRank::new(&[7, 4, 3, 2, 1])),
+        ];
+        let payouts = award_hi_lo_pots(&pots, &highs, &lows, 2, 3, OddChipRule::LeftOfButton);
+        assert_eq!(payouts, vec![(2, 22), (0, 23)]);
+
+        // No qualifying low: the high hand takes it all.
+        let payouts = award_hi_lo_pots(&pots, &highs, &[], 2, 3, OddChipRule::LeftOfButton);
+        assert_eq!(payouts, vec![(0, 45)]);
+
+        // Tied lows split the low half.
+        let lows = [
+            (1, LowRank::new(&[7, 4, 3, 2, 1])),
+            (2, LowRank::new(&[7, 4, 3, 2, 1])),
+        ];
+        let payouts = award_hi_lo_pots(&pots, &highs, &lows, 2, 3, OddChipRule::LeftOfButton);
+        assert_eq!(payouts, vec![(1, 11), (2, 11), (0, 23)]);
+    }
+}
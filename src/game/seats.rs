@@ -0,0 +1,284 @@
+use crate::game::betting::{Action, Betting, LegalAction, Stakes, blind_seats};
+use crate::game::error::GameError;
+use crate::game::pot::{OddChipRule, SidePot, award_hi_lo_pots, award_pots};
+use crate::hand::Hand;
+use crate::hand::low::LowRank;
+use crate::player::Player;
+
+///The table side of a hand every game shares: the players, the button and
+///blinds, the betting and paying out. Games keep their own cards and phases
+///and hand the rest to this.
+#[derive(Clone, Debug)]
+pub struct Seats {
+    players: Vec<Player>,
+    stakes: Stakes,
+    button: usize,
+    small_blind: Option<usize>,
+    big_blind: usize,
+    odd_chip: OddChipRule,
+    betting: Betting,
+}
+
+impl Seats {
+    //Seats are in dealing order, the button starts on the last seat. Refuses seat
+    //counts outside min-max. Stacks come from the players.
+    pub fn new(
+        players: Vec<Player>,
+        stakes: Stakes,
+        min: usize,
+        max: usize,
+    ) -> Result<Seats, GameError> {
+        if players.len() < min || players.len() > max {
+            return Err(GameError::SeatCount {
+                seats: players.len(),
+                min,
+                max,
+            });
+        }
+        let button = players.len() - 1;
+        let (small, big) = blind_seats(button, players.len());
+        let stacks = players.iter().map(|p| p.get_stack()).collect();
+        Ok(Seats {
+            stakes,
+            button,
+            small_blind: Some(small),
+            big_blind: big,
+            odd_chip: OddChipRule::default(),
+            betting: Betting::with_limit(stacks, stakes.big_blind, stakes.limit),
+            players,
+        })
+    }
+
+    // -------------------------
+    // Getters
+    // -------------------------
+
+    //Just a getter.
+    pub fn get_players(&self) -> &Vec<Player> {
+        &self.players
+    }
+    //Just a getter.
+    pub fn get_betting(&self) -> &Betting {
+        &self.betting
+    }
+    //Standard getter.
+    pub fn get_button(&self) -> usize {
+        self.button
+    }
+    //Number of seats at the table, folded or not.
+    pub fn count(&self) -> usize {
+        self.players.len()
+    }
+    //Seat whose turn it is in the current betting round. The games decide
+    //whether a round is running at all.
+    pub fn to_act(&self) -> Option<usize> {
+        self.betting.get_to_act()
+    }
+
+    //What the seat to act may do.
+    pub fn legal_actions(&self) -> Vec<LegalAction> {
+        self.betting.legal_actions()
+    }
+
+    //Every seat starting left of the button, the order cards are dealt in.
+    pub fn deal_order(&self) -> Vec<usize> {
+        let seats = self.players.len();
+        (1..=seats)
+            .map(|offset| (self.button + offset) % seats)
+            .collect()
+    }
+
+    //Seats still in the hand, starting left of the button.
+    pub fn seats_in_hand(&self) -> Vec<usize> {
+        self.deal_order()
+            .into_iter()
+            .filter(|&seat| !self.betting.has_folded(seat))
+            .collect()
+    }
+
+    // -------------------------
+    // Setup
+    // -------------------------
+
+    //Moves the button, the blinds follow it.
+    pub fn set_button(&mut self, seat: usize) -> Result<(), GameError> {
+        if seat >= self.players.len() {
+            return Err(GameError::SeatOutOfRange(seat));
+        }
+        self.button = seat;
+        let (small, big) = blind_seats(seat, self.players.len());
+        self.small_blind = Some(small);
+        self.big_blind = big;
+        Ok(())
+    }
+
+    //Overrides who posts the blinds after the button is set, eg. a dead small blind
+    //when the seat that should post it has left the table.
+    pub fn set_blinds(&mut self, small: Option<usize>, big: usize) -> Result<(), GameError> {
+        for seat in small.into_iter().chain([big]) {
+            if seat >= self.players.len() {
+                return Err(GameError::SeatOutOfRange(seat));
+            }
+        }
+        self.small_blind = small;
+        self.big_blind = big;
+        Ok(())
+    }
+
+    //Who gets odd chips on split pots, left of the button unless set.
+    pub fn set_odd_chip_rule(&mut self, rule: OddChipRule) {
+        self.odd_chip = rule;
+    }
+
+    // -------------------------
+    // Betting
+    // -------------------------
+
+    //Posts antes and blinds and opens the first betting round left of the big blind.
+    pub fn post_blinds(&mut self) {
+        self.betting
+            .start_round((self.big_blind + 1) % self.players.len());
+        self.post_antes();
+        if let Some(small) = self.small_blind {
+            self.betting.post_blind(small, self.stakes.small_blind);
+        }
+        self.betting
+            .post_blind(self.big_blind, self.stakes.big_blind);
+    }
+
+    //Posts antes and the bring-in, which opens the first betting round.
+    pub fn post_bring_in(&mut self, seat: usize) {
+        self.betting.start_round(seat);
+        self.post_antes();
+        self.betting.post_bring_in(seat, self.stakes.bring_in);
+    }
+
+    //Opens the next betting round, `first` acting first. Big bets switch fixed
+    //limit over to the big bet.
+    pub fn start_round(&mut self, first: usize, big_bets: bool) {
+        if big_bets {
+            self.betting.set_bet_unit(self.stakes.big_bet());
+        }
+        self.betting.start_round(first);
+    }
+
+    //Opens the next betting round left of the button.
+    pub fn start_round_left_of_button(&mut self, big_bets: bool) {
+        self.start_round((self.button + 1) % self.players.len(), big_bets);
+    }
+
+    //Betting action for the seat whose turn it is.
+    pub fn act(&mut self, seat: usize, action: Action) -> Result<u32, GameError> {
+        Ok(self.betting.apply(seat, action)?)
+    }
+
+    // -------------------------
+    // Payouts
+    // -------------------------
+
+    //Builds the main and side pots, pays each to the best hand that can win it
+    //(the last seat standing if everyone else folded) and writes the new stacks back
+    //to the players. Odd chips follow the odd chip rule. Returns what each winner got.
+    pub fn settle(&mut self, hands: &[(usize, &Hand)]) -> Vec<(usize, u32)> {
+        let payouts = award_pots(
+            &self.side_pots(),
+            hands,
+            self.button,
+            self.players.len(),
+            self.odd_chip,
+        );
+        self.pay_out(&payouts);
+        payouts
+    }
+
+    //Same as settle, but each pot is split with the best low (see award_hi_lo_pots).
+    pub fn settle_hi_lo(
+        &mut self,
+        hands: &[(usize, &Hand)],
+        lows: &[(usize, LowRank)],
+    ) -> Vec<(usize, u32)> {
+        let payouts = award_hi_lo_pots(
+            &self.side_pots(),
+            hands,
+            lows,
+            self.button,
+            self.players.len(),
+            self.odd_chip,
+        );
+        self.pay_out(&payouts);
+        payouts
+    }
+
+    fn post_antes(&mut self) {
+        for seat in 0..self.players.len() {
+            self.betting.post_ante(seat, self.stakes.ante);
+        }
+    }
+
+    fn side_pots(&self) -> Vec<SidePot> {
+        let folded: Vec<bool> = (0..self.players.len())
+            .map(|seat| self.betting.has_folded(seat))
+            .collect();
+        self.betting.get_pot().side_pots(&folded)
+    }
+
+    fn pay_out(&mut self, payouts: &[(usize, u32)]) {
+        for &(seat, amount) in payouts {
+            self.betting.award(seat, amount);
+        }
+        for (seat, player) in self.players.iter_mut().enumerate() {
+            player.set_stack(self.betting.get_stacks()[seat]);
+        }
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn seats(count: usize) -> Seats {
+        let players = Player::computers(&vec![100; count]);
+        Seats::new(players, Stakes::blinds(1, 2), 2, 6).unwrap()
+    }
+
+    #[test]
+    fn button_test() {
+        assert_eq!(
+            Seats::new(Player::computers(&[100]), Stakes::blinds(1, 2), 2, 6).unwrap_err(),
+            GameError::SeatCount {
+                seats: 1,
+                min: 2,
+                max: 6
+            }
+        );
+
+        let mut seats = seats(4);
+        assert_eq!(seats.get_button(), 3);
+        assert_eq!(seats.deal_order(), vec![0, 1, 2, 3]);
+        seats.set_button(1).unwrap();
+        assert_eq!(seats.deal_order(), vec![2, 3, 0, 1]);
+        assert_eq!(seats.set_button(4), Err(GameError::SeatOutOfRange(4)));
+        assert_eq!(seats.set_blinds(None, 7), Err(GameError::SeatOutOfRange(7)));
+
+        // Small blind on 2, big blind on 3, seat 0 first to act.
+        seats.post_blinds();
+        assert_eq!(seats.to_act(), Some(0));
+        assert!(!seats.legal_actions().is_empty());
+        assert_eq!(seats.get_betting().get_bets(), &vec![0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn settle_test() {
+        let mut seats = seats(3);
+        seats.post_blinds();
+        seats.act(2, Action::Fold).unwrap();
+        seats.act(0, Action::Fold).unwrap();
+        assert_eq!(seats.seats_in_hand(), vec![1]);
+
+        let hand = Hand::default();
+        assert_eq!(seats.settle(&[(1, &hand)]), vec![(1, 3)]);
+        let stacks: Vec<u32> = seats.get_players().iter().map(|p| p.get_stack()).collect();
+        assert_eq!(stacks, vec![99, 101, 100]);
+    }
+}
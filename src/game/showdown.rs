@@ -0,0 +1,116 @@
+use crate::hand::Hand;
+use crate::hand::card::PlayingCard;
+use crate::hand::hand_type::HandType;
+use crate::hand::rank::HandRank;
+
+///One seat's result at showdown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShowdownEntry {
+    seat: usize,
+    hand_type: HandType,
+    rank: HandRank,
+    cards: Vec<PlayingCard>,
+}
+
+impl ShowdownEntry {
+    //Standard getter.
+    pub fn get_seat(&self) -> usize {
+        self.seat
+    }
+    //Standard getter.
+    pub fn get_hand_type(&self) -> HandType {
+        self.hand_type.clone()
+    }
+    //Standard getter.
+    pub fn get_rank(&self) -> HandRank {
+        self.rank
+    }
+    //Deciding cards, biggest group first then kickers (see Hand::ranked_cards).
+    pub fn get_cards(&self) -> &Vec<PlayingCard> {
+        &self.cards
+    }
+}
+
+//Ranks any number of seats at once. Returns the finishing groups best first,
+//every group holding all the seats that tied for that place (in the order given),
+//so a split pot just goes to everyone in the first group.
+pub fn showdown(hands: &[(usize, &Hand)]) -> Vec<Vec<ShowdownEntry>> {
+    let mut entries: Vec<ShowdownEntry> = hands
+        .iter()
+        .map(|&(seat, hand)| ShowdownEntry {
+            seat,
+            hand_type: hand.get_hand_type(),
+            rank: hand.rank(),
+            cards: hand.ranked_cards(),
+        })
+        .collect();
+    // Stable sort keeps the given seat order within a tie.
+    entries.sort_by_key(|e| std::cmp::Reverse(e.rank));
+
+    let mut groups: Vec<Vec<ShowdownEntry>> = Vec::new();
+    for entry in entries {
+        match groups.last_mut() {
+            Some(group) if group[0].rank == entry.rank => group.push(entry),
+            _ => groups.push(vec![entry]),
+        }
+    }
+    groups
+}
+
+//Seats in the first finishing group.
+pub fn winners(groups: &[Vec<ShowdownEntry>]) -> Vec<usize> {
+    groups
+        .first()
+        .map(|group| group.iter().map(|e| e.seat).collect())
+        .unwrap_or_default()
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hand::card::Value;
+
+    #[test]
+    fn showdown_test() {
+        let broadway: Hand = "As Kd Qc Jh Ts".parse().unwrap();
+        let same_broadway: Hand = "Ac Kh Qd Js Th".parse().unwrap();
+        let aces_up: Hand = "Ad Ah 9c 9d Ks".parse().unwrap();
+        let aces_up_worse_kicker: Hand = "Ac 8s 9h 9s As".parse().unwrap();
+        let eights: Hand = "8c 8d 4h 3s 2d".parse().unwrap();
+
+        let groups = showdown(&[
+            (3, &aces_up_worse_kicker),
+            (1, &same_broadway),
+            (5, &eights),
+            (0, &broadway),
+            (2, &aces_up),
+        ]);
+        assert_eq!(groups.len(), 4);
+        assert_eq!(winners(&groups), vec![1, 0]);
+        assert_eq!(groups[0][0].get_hand_type(), HandType::Straight);
+        assert_eq!(groups[1][0].get_seat(), 2);
+        assert_eq!(groups[2][0].get_seat(), 3);
+        assert_eq!(groups[3][0].get_seat(), 5);
+
+        // Deciding cards: the pairs first, kicker last.
+        let values: Vec<Value> = groups[1][0]
+            .get_cards()
+            .iter()
+            .map(|c| c.get_value())
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                Value::Ace,
+                Value::Ace,
+                Value::Nine,
+                Value::Nine,
+                Value::King
+            ]
+        );
+
+        assert!(showdown(&[]).is_empty());
+        assert!(winners(&[]).is_empty());
+    }
+}
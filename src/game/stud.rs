@@ -0,0 +1,439 @@
+use std::cmp::Reverse;
+
+use crate::deck::Deck;
+use crate::game::betting::{Action, Betting, LegalAction, Stakes};
+use crate::game::error::GameError;
+use crate::game::pot::OddChipRule;
+use crate::game::seats::Seats;
+use crate::game::showdown::{ShowdownEntry, showdown};
+use crate::hand::Hand;
+use crate::hand::card::{PlayingCard, SuitRanking};
+use crate::hand::error::CardError;
+use crate::hand::evaluator::best_hand;
+use crate::player::Player;
+
+// CONSTANTS
+pub const MIN_SEATS: usize = 2;
+pub const MAX_SEATS: usize = 7;
+const DOWN_CARDS: usize = 3;
+const UP_CARDS: usize = 4;
+
+///Where a seven card stud hand is up to. Each street is its own betting round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StudPhase {
+    Dealing,
+    ThirdStreet,
+    FourthStreet,
+    FifthStreet,
+    SixthStreet,
+    SeventhStreet,
+    Showdown,
+    Complete,
+}
+
+///Seven card stud: antes, two down and one up, the lowest up card brings it in,
+///then three more up cards and a last down card with a betting round on each.
+///Later streets are opened by the best hand showing. Seats still in at the end
+///play their best five of seven.
+#[derive(Clone, Debug)]
+pub struct StudGame {
+    seats: Seats,
+    down_cards: Vec<Hand>,
+    up_cards: Vec<Hand>,
+    deck: Deck,
+    suit_ranking: SuitRanking,
+    bring_in: Option<usize>,
+    phase: StudPhase,
+}
+
+impl StudGame {
+    //Seats are in dealing order. Refuses seat counts outside 2-7, as eight players
+    //could run the deck out, and decks with jokers as stud has no wild cards.
+    //Stacks come from the players.
+    pub fn new(players: Vec<Player>, deck: Deck, stakes: Stakes) -> Result<StudGame, GameError> {
+        let mut seats = Seats::new(players, stakes, MIN_SEATS, MAX_SEATS)?;
+        if let Some(joker) = deck.find_joker() {
+            return Err(CardError::WildCard(joker).into());
+        }
+        // Stud has no button. Leaving it on the last seat keeps everything in seat order.
+        seats.set_odd_chip_rule(OddChipRule::LowestSeat);
+        Ok(StudGame {
+            down_cards: vec![Hand::new(DOWN_CARDS); seats.count()],
+            up_cards: vec![Hand::new(UP_CARDS); seats.count()],
+            deck,
+            suit_ranking: SuitRanking::default(),
+            bring_in: None,
+            phase: StudPhase::Dealing,
+            seats,
+        })
+    }
+
+    // -------------------------
+    // Getters
+    // -------------------------
+
+    //Just a getter.
+    pub fn get_players(&self) -> &Vec<Player> {
+        self.seats.get_players()
+    }
+    //Face down cards, only the seat itself gets to see these.
+    pub fn get_down_cards(&self) -> &Vec<Hand> {
+        &self.down_cards
+    }
+    //Face up cards, everyone sees these.
+    pub fn get_up_cards(&self) -> &Vec<Hand> {
+        &self.up_cards
+    }
+    //Just a getter.
+    pub fn get_deck(&self) -> &Deck {
+        &self.deck
+    }
+    //Just a getter.
+    pub fn get_betting(&self) -> &Betting {
+        self.seats.get_betting()
+    }
+    //Standard getter.
+    pub fn get_phase(&self) -> StudPhase {
+        self.phase
+    }
+    //Standard getter.
+    pub fn get_suit_ranking(&self) -> SuitRanking {
+        self.suit_ranking
+    }
+    //Seat that brought it in on third street.
+    pub fn get_bring_in(&self) -> Option<usize> {
+        self.bring_in
+    }
+    //Every card a seat holds, down cards first.
+    pub fn get_cards(&self, seat: usize) -> Vec<PlayingCard> {
+        let mut cards = self.down_cards[seat].get_hand().clone();
+        cards.extend(self.up_cards[seat].get_hand().iter().copied());
+        cards
+    }
+    //Seat whose turn it is to bet, if a betting round is running.
+    pub fn to_act(&self) -> Option<usize> {
+        match self.phase {
+            StudPhase::Dealing | StudPhase::Showdown | StudPhase::Complete => None,
+            _ => self.seats.to_act(),
+        }
+    }
+
+    //What the seat to act may do, empty when nobody is betting.
+    pub fn legal_actions(&self) -> Vec<LegalAction> {
+        match self.to_act() {
+            Some(_) => self.seats.legal_actions(),
+            None => Vec::new(),
+        }
+    }
+
+    //Suit order for the bring-in, bridge order unless set. Only allowed before the deal.
+    pub fn set_suit_ranking(&mut self, ranking: SuitRanking) -> Result<(), GameError> {
+        if self.phase != StudPhase::Dealing {
+            return Err(GameError::WrongPhase);
+        }
+        self.suit_ranking = ranking;
+        Ok(())
+    }
+
+    //Who gets odd chips on split pots, lowest seat unless set (stud has no button).
+    pub fn set_odd_chip_rule(&mut self, rule: OddChipRule) {
+        self.seats.set_odd_chip_rule(rule);
+    }
+
+    // -------------------------
+    // Game Flow
+    // -------------------------
+
+    //Posts antes, deals two down and one up to every seat, and has the lowest up
+    //card bring it in.
+    pub fn deal(&mut self) -> Result<(), GameError> {
+        if self.phase != StudPhase::Dealing {
+            return Err(GameError::WrongPhase);
+        }
+        for _i in 0..2 {
+            for seat in self.seats.deal_order() {
+                self.deck.deal_to(&mut self.down_cards[seat])?;
+            }
+        }
+        for seat in self.seats.deal_order() {
+            self.deck.deal_to(&mut self.up_cards[seat])?;
+        }
+        self.check_conservation();
+
+        let bring_in = self.lowest_up_card();
+        self.seats.post_bring_in(bring_in);
+        self.bring_in = Some(bring_in);
+        self.phase = StudPhase::ThirdStreet;
+        self.advance()
+    }
+
+    //Betting action for the seat whose turn it is.
+    pub fn act(&mut self, seat: usize, action: Action) -> Result<u32, GameError> {
+        if self.to_act().is_none() {
+            return Err(GameError::WrongPhase);
+        }
+        let paid = self.seats.act(seat, action)?;
+        self.advance()?;
+        Ok(paid)
+    }
+
+    //Seats still in the hand.
+    pub fn seats_in_hand(&self) -> Vec<usize> {
+        self.seats.seats_in_hand()
+    }
+
+    //Seat with the lowest up card (Aces high), ties broken by the suit ranking.
+    pub fn lowest_up_card(&self) -> usize {
+        (0..self.seats.count())
+            .filter_map(|seat| self.up_cards[seat].get_hand().first().map(|&c| (seat, c)))
+            .min_by_key(|&(_, card)| {
+                (
+                    card.numeric_value(),
+                    self.suit_ranking.rank(card.get_suit()),
+                )
+            })
+            .map_or(0, |(seat, _)| seat)
+    }
+
+    //Seat still in with the best hand showing, opens the betting from fourth street on.
+    //Only pairs, trips and quads count on the board, then high cards. Ties go to
+    //the lowest seat.
+    pub fn best_showing(&self) -> usize {
+        self.seats_in_hand()
+            .into_iter()
+            .min_by_key(|&seat| Reverse(showing_strength(self.up_cards[seat].get_hand())))
+            .unwrap_or(0)
+    }
+
+    //Best five of seven for a seat.
+    pub fn best_hand(&self, seat: usize) -> Option<Hand> {
+        self.evaluate(seat).ok()
+    }
+
+    //Finishing groups among the seats still in, best hand first, ties grouped together.
+    pub fn showdown(&self) -> Vec<Vec<ShowdownEntry>> {
+        let best: Vec<(usize, Hand)> = self
+            .seats_in_hand()
+            .into_iter()
+            .filter_map(|seat| self.best_hand(seat).map(|hand| (seat, hand)))
+            .collect();
+        let hands: Vec<(usize, &Hand)> = best.iter().map(|(seat, hand)| (*seat, hand)).collect();
+        showdown(&hands)
+    }
+
+    //Pays out the best hands of the seats still in, see Seats::settle.
+    pub fn settle(&mut self) -> Result<Vec<(usize, u32)>, GameError> {
+        if self.phase != StudPhase::Showdown {
+            return Err(GameError::WrongPhase);
+        }
+        let in_hand = self.seats_in_hand();
+        let best: Vec<(usize, Hand)> = if in_hand.len() == 1 {
+            vec![(in_hand[0], self.up_cards[in_hand[0]].clone())]
+        } else {
+            in_hand
+                .iter()
+                .map(|&seat| self.evaluate(seat).map(|hand| (seat, hand)))
+                .collect::<Result<_, _>>()?
+        };
+        let hands: Vec<(usize, &Hand)> = best.iter().map(|(seat, hand)| (*seat, hand)).collect();
+        let payouts = self.seats.settle(&hands);
+        self.phase = StudPhase::Complete;
+        Ok(payouts)
+    }
+
+    // Moves the hand on once a betting round is finished: deals the next street to
+    // everyone still in and opens its betting with the best hand showing. If
+    // everyone else folded it goes straight to the showdown, if everyone left is
+    // all in the rest of the cards are dealt out first.
+    fn advance(&mut self) -> Result<(), GameError> {
+        loop {
+            if self.seats.get_betting().players_in_hand().len() <= 1 {
+                self.phase = StudPhase::Showdown;
+                return Ok(());
+            }
+            if !self.seats.get_betting().is_round_complete() {
+                return Ok(());
+            }
+            let next = match self.phase {
+                StudPhase::ThirdStreet => StudPhase::FourthStreet,
+                StudPhase::FourthStreet => StudPhase::FifthStreet,
+                StudPhase::FifthStreet => StudPhase::SixthStreet,
+                StudPhase::SixthStreet => StudPhase::SeventhStreet,
+                StudPhase::SeventhStreet => {
+                    self.phase = StudPhase::Showdown;
+                    return Ok(());
+                }
+                _ => return Ok(()),
+            };
+            for seat in self.seats_in_hand() {
+                if next == StudPhase::SeventhStreet {
+                    self.deck.deal_to(&mut self.down_cards[seat])?;
+                } else {
+                    self.deck.deal_to(&mut self.up_cards[seat])?;
+                }
+            }
+            self.check_conservation();
+            // Fifth street on plays for the big bet in fixed limit.
+            let first = self.best_showing();
+            self.seats
+                .start_round(first, next == StudPhase::FifthStreet);
+            self.phase = next;
+        }
+    }
+
+    // Same as best_hand, but says why there isn't one.
+    fn evaluate(&self, seat: usize) -> Result<Hand, GameError> {
+        if seat >= self.seats.count() {
+            return Err(GameError::SeatOutOfRange(seat));
+        }
+        Ok(best_hand(&self.get_cards(seat))?)
+    }
+
+    fn check_conservation(&self) {
+        let hands: Vec<&Hand> = self.down_cards.iter().chain(self.up_cards.iter()).collect();
+        self.deck.debug_check_conservation(&hands, &[]);
+    }
+}
+
+//Strength of the cards showing for deciding who acts first: the sizes of the
+//groups of matching values then their values, biggest groups first.
+//eg. a pair of Kings with an Ace showing is [2, 13, 1, 14].
+pub fn showing_strength(cards: &[PlayingCard]) -> Vec<u8> {
+    let mut groups: Vec<(u8, u8)> = Vec::new();
+    for card in cards {
+        match groups.iter_mut().find(|(_, v)| *v == card.numeric_value()) {
+            Some(group) => group.0 += 1,
+            None => groups.push((1, card.numeric_value())),
+        }
+    }
+    groups.sort_by_key(|&group| Reverse(group));
+    groups
+        .into_iter()
+        .flat_map(|(count, value)| [count, value])
+        .collect()
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hand::card::Suit;
+    use crate::hand::hand_type::HandType;
+
+    fn cards(s: &str) -> Vec<PlayingCard> {
+        s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn showing_strength_test() {
+        assert!(showing_strength(&cards("Kc Kd 2s")) > showing_strength(&cards("Ac Qd Js")));
+        assert!(showing_strength(&cards("2c 2d 2s")) > showing_strength(&cards("Ac Ad Ks")));
+        assert!(showing_strength(&cards("Ac 9d")) > showing_strength(&cards("Ac 8d")));
+        assert_eq!(showing_strength(&cards("Ah Kc Kd")), vec![2, 13, 1, 14]);
+    }
+
+    #[test]
+    fn bring_in_test() {
+        // Down cards go round twice, then the up cards: seat 0 Kh, seat 1 2c, seat 2 2s.
+        let deck = Deck::rigged("Ac Ad As Ah Kc Kd Kh 2c 2s");
+        let mut game = StudGame::new(
+            Player::computers(&[100, 100, 100]),
+            deck.clone(),
+            Stakes::stud(1, 1, 2),
+        )
+        .unwrap();
+        game.deal().unwrap();
+        // Bridge order puts clubs lowest.
+        assert_eq!(game.get_bring_in(), Some(1));
+        assert_eq!(game.get_betting().get_pot().total(), 4);
+        assert_eq!(game.to_act(), Some(2));
+        // Seat 2 may call the bring-in or complete to a full small bet.
+        assert_eq!(
+            game.legal_actions(),
+            vec![
+                LegalAction::Fold,
+                LegalAction::Call(1),
+                LegalAction::Raise { min: 2, max: 2 }
+            ]
+        );
+
+        let mut game = StudGame::new(
+            Player::computers(&[100, 100, 100]),
+            deck,
+            Stakes::stud(1, 1, 2),
+        )
+        .unwrap();
+        game.set_suit_ranking(
+            SuitRanking::new([Suit::Spade, Suit::Heart, Suit::Diamond, Suit::Club]).unwrap(),
+        )
+        .unwrap();
+        game.deal().unwrap();
+        assert_eq!(game.get_bring_in(), Some(2));
+        assert_eq!(game.to_act(), Some(0));
+        assert_eq!(
+            game.set_suit_ranking(SuitRanking::default()),
+            Err(GameError::WrongPhase)
+        );
+
+        // No wild cards in stud.
+        let jokers = Deck::with_jokers(1).unwrap();
+        assert!(matches!(
+            StudGame::new(
+                Player::computers(&[100, 100]),
+                jokers,
+                Stakes::stud(1, 1, 2)
+            ),
+            Err(GameError::Card(CardError::WildCard(_)))
+        ));
+    }
+
+    #[test]
+    fn stud_hand_test() {
+        // Seat 0: Ac Ad / 9h, then 9c 4s 2h / 5d.
+        // Seat 1: Kc Kd / 3c, then Qs Qh 8c / 6s.
+        let deck = Deck::rigged("Ac Kc Ad Kd 9h 3c 9c Qs 4s Qh 2h 8c 5d 6s");
+        let mut game =
+            StudGame::new(Player::computers(&[100, 100]), deck, Stakes::stud(1, 1, 2)).unwrap();
+        game.deal().unwrap();
+        assert_eq!(game.get_phase(), StudPhase::ThirdStreet);
+        assert_eq!(game.get_bring_in(), Some(1));
+        game.act(0, Action::Raise(2)).unwrap();
+        game.act(1, Action::Call).unwrap();
+
+        // Seat 0 shows a pair of nines and opens fourth street.
+        assert_eq!(game.get_phase(), StudPhase::FourthStreet);
+        assert_eq!(game.get_up_cards()[0].get_hand().len(), 2);
+        assert_eq!(game.to_act(), Some(0));
+        game.act(0, Action::Check).unwrap();
+        game.act(1, Action::Check).unwrap();
+
+        // Queens showing beat nines showing on fifth street, and the bet doubles.
+        assert_eq!(game.get_phase(), StudPhase::FifthStreet);
+        assert_eq!(game.to_act(), Some(1));
+        assert_eq!(game.legal_actions()[2], LegalAction::Bet { min: 4, max: 4 });
+        game.act(1, Action::Bet(4)).unwrap();
+        game.act(0, Action::Call).unwrap();
+        game.act(1, Action::Check).unwrap();
+        game.act(0, Action::Check).unwrap();
+
+        assert_eq!(game.get_phase(), StudPhase::SeventhStreet);
+        assert_eq!(game.get_down_cards()[0].get_hand().len(), 3);
+        assert_eq!(game.get_cards(1).len(), 7);
+        game.act(1, Action::Check).unwrap();
+        game.act(0, Action::Check).unwrap();
+
+        assert_eq!(game.get_phase(), StudPhase::Showdown);
+        assert_eq!(
+            game.best_hand(0).unwrap().get_hand_type(),
+            HandType::TwoPair
+        );
+        assert_eq!(
+            game.best_hand(1).unwrap().get_hand_type(),
+            HandType::TwoPair
+        );
+        // Aces up beats kings up.
+        assert_eq!(game.settle(), Ok(vec![(0, 14)]));
+        let stacks: Vec<u32> = game.get_players().iter().map(|p| p.get_stack()).collect();
+        assert_eq!(stacks, vec![107, 93]);
+    }
+}
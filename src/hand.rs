@@ -1,13 +1,22 @@
 use core::fmt;
-use rand::rng;
-use rand::seq::SliceRandom;
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::str::FromStr;
 
 pub mod card;
+pub mod card_set;
+pub mod describe;
+pub mod error;
+pub mod evaluator;
+pub mod frequency;
 pub mod hand_type;
-use card::{PlayingCard, Suit, Value};
+pub mod lookup;
+pub mod low;
+pub mod rank;
+pub mod wild;
+use card::{ParseCardError, PlayingCard, Suit};
+use error::CardError;
 use hand_type::HandType;
+use rank::HandRank;
 
 // Could be simplified significantly by embedding various parts into the enum
 // Learned about that after writing this though, so I'll have to refactor eventually.
@@ -15,9 +24,11 @@ use hand_type::HandType;
 // CONSTANTS
 const DEFAULT_HAND_SIZE: usize = 5;
 const ACE_VALUE: u8 = 14;
+// Highest value a card can report, an unplayed joker sits above the ace.
+const JOKER_VALUE: u8 = 15;
 
 ///Hand structure
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Hand {
     hand: Vec<card::PlayingCard>,
     max_size: usize,
@@ -32,6 +43,7 @@ impl Hand {
     //Default constructor.
     // Likely won't be used, probs will remove in favor of 'from' constructor.
     // Creates hand at default poker size (5 - magic number issue??)
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Hand {
         let mut hand = Hand {
             hand: Vec::new(),
@@ -41,8 +53,22 @@ impl Hand {
         hand.hand_type = HandType::None;
         hand
     }
-    //Constructs Hand obj from a given vector
-    pub fn from(v: Vec<PlayingCard>) -> Hand {
+    //Constructs Hand obj from a given vector. Same as draw, a card can only be
+    //in the hand once.
+    pub fn from(v: Vec<PlayingCard>) -> Result<Hand, CardError> {
+        if let Some(card) = first_duplicate(&v) {
+            return Err(CardError::DuplicateCard(card));
+        }
+        Ok(Hand::build(v))
+    }
+
+    //Hand made of the cards wild cards were played as. A wild card can stand in
+    //for a card that's already there (eg. five of a kind), so repeats are allowed.
+    pub fn from_played(v: Vec<PlayingCard>) -> Hand {
+        Hand::build(v)
+    }
+
+    fn build(v: Vec<PlayingCard>) -> Hand {
         let mut hand = Hand {
             hand: v.clone(),
             max_size: v.len(),
@@ -54,8 +80,8 @@ impl Hand {
         hand
     }
 
-    //Allows creation of hands of custom sizes.
-    fn new(m_size: usize) -> Hand {
+    //Allows creation of hands of custom sizes (eg. two hole cards).
+    pub fn new(m_size: usize) -> Hand {
         Hand {
             hand: Vec::new(),
             max_size: m_size,
@@ -63,258 +89,97 @@ impl Hand {
         }
     }
 
-    pub fn generate_deck() -> Hand {
-        let mut hand: Hand = Hand::new(52);
-        hand.hand_type = HandType::Deck;
-        for &suit in &[Suit::Club, Suit::Spade, Suit::Heart, Suit::Diamond] {
-            for &value in &[
-                Value::Ace,
-                Value::King,
-                Value::Queen,
-                Value::Jack,
-                Value::Ten,
-                Value::Nine,
-                Value::Eight,
-                Value::Seven,
-                Value::Six,
-                Value::Five,
-                Value::Four,
-                Value::Three,
-                Value::Two,
-            ] {
-                let _ = hand.draw(PlayingCard::new(suit, value));
-            }
-        }
-        hand.hand.shuffle(&mut rng());
-        hand
-    }
-
     // -------------------------
     // Card Operations
     // -------------------------
 
     //Draws a card, essentially a single-purpose setter.
-    pub fn draw(&mut self, card: PlayingCard) -> Result<PlayingCard, &str> {
-        if self.hand.len() < self.max_size.into() {
-            self.hand.push(card);
-            if self.hand.len() == self.max_size {
-                self.hand_type = self.check_hand_type();
-            }
-            Ok(card)
-        } else {
-            Err("Hand is full")
+    pub fn draw(&mut self, card: PlayingCard) -> Result<PlayingCard, CardError> {
+        if self.hand.len() >= self.max_size {
+            return Err(CardError::HandFull);
+        }
+        if self.hand.contains(&card) {
+            return Err(CardError::DuplicateCard(card));
         }
+        self.hand.push(card);
+        if self.hand.len() == self.max_size {
+            self.hand_type = self.check_hand_type();
+        }
+        Ok(card)
     }
     //Just a getter.
     pub fn get_hand(&self) -> &Vec<PlayingCard> {
         &self.hand
     }
+    //Standard getter.
+    pub fn get_hand_type(&self) -> HandType {
+        self.hand_type.clone()
+    }
+
+    //True once the hand holds max_size cards.
+    pub fn is_full(&self) -> bool {
+        self.hand.len() >= self.max_size
+    }
 
     //Peeks at the top card without discarding
     pub fn peek(&mut self) -> Option<PlayingCard> {
-        if self.hand.len() != 0 {
+        if !self.hand.is_empty() {
             return Some(self.hand[0]);
         }
         None
     }
 
-    pub fn deal(&mut self, other: &mut Hand) {
-        if other.hand.len() < other.max_size && self.hand.len() > 0 {
-            other
-                .draw(self.discard(0).unwrap())
-                .expect("Already checks possible fail conditions.");
+    //Moves the top card into another hand. The card is only taken off this
+    //hand once the other hand has accepted it, so nothing gets lost on a failure.
+    pub fn deal(&mut self, other: &mut Hand) -> Result<PlayingCard, CardError> {
+        if other.is_full() {
+            return Err(CardError::HandFull);
         }
+        let card = self.peek().ok_or(CardError::DeckEmpty)?;
+        other.draw(card)?;
+        self.hand.remove(0);
+        Ok(card)
     }
 
     //Removes a card at a given index. Due to the nature of Vec cards seem to propogate to lowest possible indexes.
     //Need to test that tho.
-    pub fn discard(&mut self, index: usize) -> Option<PlayingCard> {
+    pub fn discard(&mut self, index: usize) -> Result<PlayingCard, CardError> {
         if index < self.hand.len() {
-            Some(self.hand.remove(index))
+            Ok(self.hand.remove(index))
         } else {
-            None
+            Err(CardError::IndexOutOfRange {
+                index,
+                len: self.hand.len(),
+            })
         }
     }
 
-    pub fn group_discard(&mut self, mut indexes: Vec<usize>) {
+    //Removes every card at the given indexes, checking all of them before touching the hand.
+    //Repeated indexes only discard once. Returns the discarded cards.
+    pub fn group_discard(
+        &mut self,
+        mut indexes: Vec<usize>,
+    ) -> Result<Vec<PlayingCard>, CardError> {
+        if let Some(&index) = indexes.iter().find(|&&i| i >= self.hand.len()) {
+            return Err(CardError::IndexOutOfRange {
+                index,
+                len: self.hand.len(),
+            });
+        }
         indexes.sort();
+        indexes.dedup();
         indexes.reverse();
+        let mut discarded = Vec::new();
         for num in indexes {
-            self.hand.remove(num);
+            discarded.push(self.hand.remove(num));
         }
+        Ok(discarded)
     }
 
     // -------------------------------
     // HAND COMPARISON / EVALUATION
     // -------------------------------
 
-    /*
-    * Used to compare hands when one hand doesn't supercede another by ID alone
-    *   - Royal Flushes will always tie.
-        - Any straight will always be compared by highest card.
-        - Flushes/High cards will always be compared by iterating down till a difference is found.
-        - Four/Three kind will always have a set card in specific spot due to sorting - if
-          a card is pulled from the middle, then it should match up no matter what when cards are sorted by value.
-                        - 0 0 0 x x
-                        - 0 0 0 0 x
-        - Two Pair, Pair, and Full House are explaine within match function.
-     */
-    fn compare_equal_hands(&self, other: Hand) -> Ordering {
-        match self.hand_type {
-            HandType::RoyalFlush => {
-                //ALWAYS A DRAW BY NATURE
-                Ordering::Equal
-            }
-
-            HandType::StraightFlush => {
-                //Compare top card in hand!
-                self.get_hand()[0].cmp(&other.get_hand()[0])
-            }
-
-            HandType::FourKind => {
-                //Compare middle card in hand!
-                self.get_hand()[2].cmp(&other.get_hand()[2])
-            }
-
-            HandType::FullHouse => {
-                //Compare Three of a Kinds, then compare two of a kinds!
-                // Self three card
-                // Self two card
-                // Other three card
-                // Other two card
-                let self_three_card: PlayingCard;
-                let self_two_card: PlayingCard;
-                let other_three_card: PlayingCard;
-                let other_two_card: PlayingCard;
-
-                if self.get_hand()[0] == self.get_hand()[2] {
-                    self_three_card = self.get_hand()[0];
-                    self_two_card = self.get_hand()[4];
-                } else {
-                    self_three_card = self.get_hand()[4];
-                    self_two_card = self.get_hand()[0];
-                }
-                if other.get_hand()[0] == other.get_hand()[2] {
-                    other_three_card = self.get_hand()[0];
-                    other_two_card = self.get_hand()[4];
-                } else {
-                    other_three_card = self.get_hand()[4];
-                    other_two_card = self.get_hand()[0];
-                }
-                if self_three_card.cmp(&other_three_card) == Ordering::Equal {
-                    self_two_card.cmp(&other_two_card)
-                } else {
-                    self_three_card.cmp(&other_three_card)
-                }
-            }
-
-            HandType::Flush => {
-                //Compare top card in hand!
-                for i in 0..self.get_hand().len() {
-                    if self.get_hand()[i].cmp(&other.get_hand()[i]) != Ordering::Equal {
-                        return self.get_hand()[i].cmp(&other.get_hand()[i]);
-                    }
-                }
-                Ordering::Equal
-            }
-
-            HandType::Straight => {
-                // Compare top card in hand!
-                self.get_hand()[0].cmp(&other.get_hand()[0])
-            }
-
-            HandType::ThreeKind => {
-                //Compare middle card
-                self.get_hand()[2].cmp(&other.get_hand()[2])
-            }
-
-            HandType::TwoPair => {
-                //Compare each hands highest hand.
-                //If those match, compare other hand.
-                //If those match, compare remaining non-pair card.
-                // Since a two pair can be broken down into three parts: the higher pair, the lower pair, and the single kicker,
-                // and since the hand is ordered, pairs auto sort themselves, meaning there is three possible orders for a two pair hand:
-                //              - AA BB C
-                //              - AA C BB
-                //              - C AA BB
-                // Where AA is the first pair, BB is the second pair, and C is a kicker.
-                let self_higher_pair: PlayingCard;
-                let self_lower_pair: PlayingCard;
-                let self_kicker: PlayingCard;
-                let other_higher_pair: PlayingCard;
-                let other_lower_pair: PlayingCard;
-                let other_kicker: PlayingCard;
-                if self.get_hand()[0] == self.get_hand()[1] {
-                    self_higher_pair = self.get_hand()[0];
-                    if self.get_hand()[4] == self.get_hand()[3] {
-                        self_lower_pair = self.get_hand()[4];
-                        self_kicker = self.get_hand()[2];
-                    } else {
-                        self_lower_pair = self.get_hand()[2];
-                        self_kicker = self.get_hand()[4];
-                    }
-                } else {
-                    self_kicker = self.get_hand()[0];
-                    self_higher_pair = self.get_hand()[1];
-                    self_lower_pair = self.get_hand()[3];
-                }
-
-                if other.get_hand()[0] == other.get_hand()[1] {
-                    other_higher_pair = other.get_hand()[0];
-                    if other.get_hand()[4] == other.get_hand()[3] {
-                        other_lower_pair = other.get_hand()[4];
-                        other_kicker = other.get_hand()[2];
-                    } else {
-                        other_lower_pair = other.get_hand()[2];
-                        other_kicker = other.get_hand()[4];
-                    }
-                } else {
-                    other_kicker = other.get_hand()[0];
-                    other_higher_pair = other.get_hand()[1];
-                    other_lower_pair = other.get_hand()[3];
-                }
-
-                if self_higher_pair.cmp(&other_higher_pair) == Ordering::Equal {
-                    if self_lower_pair.cmp(&other_lower_pair) == Ordering::Equal {
-                        self_kicker.cmp(&other_kicker)
-                    } else {
-                        self_lower_pair.cmp(&other_lower_pair)
-                    }
-                } else {
-                    self_higher_pair.cmp(&other_higher_pair)
-                }
-            }
-
-            HandType::Pair => {
-                // Pairs will incorrectly report EQUAL when both are equal but a kicker exists - need to update.
-                // Need better practice than assigning a default value here.
-                let mut self_pair: PlayingCard = PlayingCard::new(Suit::Club, Value::Eight);
-                let mut other_pair: PlayingCard = PlayingCard::new(Suit::Club, Value::Eight);
-                for j in 0..(self.get_hand().len() - 1) {
-                    if self.get_hand()[j].cmp(&self.get_hand()[j + 1]) == Ordering::Equal {
-                        self_pair = self.get_hand()[j];
-                    }
-                    if other.get_hand()[j].cmp(&other.get_hand()[j + 1]) == Ordering::Equal {
-                        other_pair = other.get_hand()[j];
-                    }
-                }
-                self_pair.cmp(&other_pair)
-            }
-
-            HandType::HighCard => {
-                // Compare highest card. If equal, go in sequence till theres a difference.
-                for i in 0..self.get_hand().len() {
-                    if self.get_hand()[i].cmp(&other.get_hand()[i]) != Ordering::Equal {
-                        return self.get_hand()[i].cmp(&other.get_hand()[i]);
-                    }
-                }
-                Ordering::Equal
-            }
-
-            _ => Ordering::Equal,
-        }
-    }
-
     // Checks if all cards posses the same 'suit' attribute.
     fn is_flush(&self) -> bool {
         let suit: Suit = self.get_hand()[0].get_suit();
@@ -328,19 +193,13 @@ impl Hand {
     // Checks if all card values are within one of the next.
     // Fails on straights using both a 2 and an ace - need to
     // fix that. Otherwise fine.
-    fn is_straight(&mut self) -> bool {
-        print!(
-            "Sorted hand: {:?}",
-            self.hand
-                .iter()
-                .map(|c| c.numeric_value())
-                .collect::<Vec<_>>()
-        );
-        if self.get_hand()[0].numeric_value() == 14 && self.get_hand()[1].numeric_value() == 5 {
+    fn is_straight(&self) -> bool {
+        if self.get_hand()[0].numeric_value() == ACE_VALUE
+            && self.get_hand()[1].numeric_value() == 5
+        {
             for i in 1..self.hand.len() - 1 {
                 if self.get_hand()[i].numeric_value() - self.get_hand()[i + 1].numeric_value() != 1
                 {
-                    println!(" Result: False");
                     return false;
                 }
             }
@@ -348,14 +207,16 @@ impl Hand {
         }
         for i in 0..self.hand.len() - 1 {
             if self.get_hand()[i].numeric_value() - self.get_hand()[i + 1].numeric_value() != 1 {
-                println!(" Result: False");
                 return false;
             }
         }
-        println!(" Result: True");
-
         true
     }
+    // Only possible with wild cards standing in for a value that's already there.
+    fn is_five_kind(&self) -> bool {
+        let value = self.get_hand()[0].get_value();
+        self.get_hand().iter().all(|c| c.get_value() == value)
+    }
     //Checks all possible set/pair combinations.
     //Pseudo-Code:
     /* if a key's value is 4
@@ -374,67 +235,61 @@ impl Hand {
 
      */
     fn check_pairs(&self) -> HandType {
-        let mut hmap = HashMap::<u8, u8>::new();
+        let mut counts = [0u8; JOKER_VALUE as usize + 1];
         for c in self.get_hand() {
-            let num = c.numeric_value();
-            if hmap.contains_key(&num) {
-                hmap.insert(num, hmap.get(&num).unwrap() + 1);
-            } else {
-                hmap.insert(num, 1);
-            }
+            counts[c.numeric_value() as usize] += 1;
         }
-        for (key, val) in &hmap {
-            if *val == 4 {
-                return HandType::FourKind;
-            } else if *val == 3 || *val == 2 {
-                for (sub_key, sub_val) in &hmap {
-                    if *sub_key != *key {
-                        if *sub_val == 3 {
-                            return HandType::FullHouse;
-                        }
-                        if *sub_val == 2 {
-                            if *val == 2 {
-                                return HandType::TwoPair;
-                            } else {
-                                return HandType::FullHouse;
-                            }
-                        }
-                    }
-                }
-                if *val == 3 {
-                    return HandType::ThreeKind;
-                } else {
-                    return HandType::Pair;
-                }
-            }
+        let mut groups: Vec<u8> = counts.into_iter().filter(|&n| n > 0).collect();
+        groups.sort();
+        groups.reverse();
+        match groups.as_slice() {
+            [4, ..] => HandType::FourKind,
+            [3, 2, ..] => HandType::FullHouse,
+            [3, ..] => HandType::ThreeKind,
+            [2, 2, ..] => HandType::TwoPair,
+            [2, ..] => HandType::Pair,
+            _ => HandType::HighCard,
         }
-        HandType::HighCard
     }
 
     fn check_hand_type(&mut self) -> HandType {
-        if self.hand_type == HandType::Deck {
-            return HandType::Deck;
+        // Partial hands (eg. parsed from "As Kd") don't have a type yet, and neither
+        // do hands holding a joker until it's swapped for a card (see wild.rs).
+        // Otherwise its value of 15 reads eg. A K Q J Jk as a straight.
+        if self.hand.len() < DEFAULT_HAND_SIZE || self.hand.iter().any(|c| c.is_joker()) {
+            return HandType::None;
         }
         self.hand.sort();
         self.hand.reverse();
 
+        // Five of a kind is made of wild cards that can share a suit, so it has to
+        // be caught before the flush check.
+        if self.is_five_kind() {
+            return HandType::FiveKind;
+        }
+
+        // Second card has to be checked too, otherwise a 5-high wheel (A 5 4 3 2)
+        // sorts its ace to the front and gets read as a royal flush.
         if self.is_flush() && self.is_straight() {
-            if self.hand[0].numeric_value() == ACE_VALUE {
-                return HandType::RoyalFlush;
+            if self.hand[0].numeric_value() == ACE_VALUE
+                && self.hand[1].numeric_value() == ACE_VALUE - 1
+            {
+                HandType::RoyalFlush
             } else {
-                return HandType::StraightFlush;
+                HandType::StraightFlush
             }
         } else if self.is_flush() {
-            return HandType::Flush;
+            HandType::Flush
         } else if self.is_straight() {
-            return HandType::Straight;
+            HandType::Straight
         } else {
-            return self.check_pairs();
+            self.check_pairs()
         }
     }
 
     fn hand_value(&self) -> u8 {
         match self.hand_type {
+            HandType::FiveKind => 11,
             HandType::RoyalFlush => 10,
             HandType::StraightFlush => 9,
             HandType::FourKind => 8,
@@ -446,18 +301,66 @@ impl Hand {
             HandType::Pair => 2,
             HandType::HighCard => 1,
             HandType::None => 0,
-            HandType::Deck => 0,
         }
     }
+
+    //Total-order rank of the hand, including every tiebreak card.
+    pub fn rank(&self) -> HandRank {
+        HandRank::new(self.hand_value(), &self.tiebreak_values())
+    }
+
+    // Distinct card values in the order they settle a tie: bigger groups first
+    // (trips before the pair in a full house), then higher values. A wheel's
+    // ace plays low, so 5 4 3 2 A comes out as 5 4 3 2 1.
+    fn tiebreak_values(&self) -> Vec<u8> {
+        let mut counts = [0u8; JOKER_VALUE as usize + 1];
+        for c in self.get_hand() {
+            counts[c.numeric_value() as usize] += 1;
+        }
+        let mut groups: Vec<(u8, u8)> = (2..=ACE_VALUE)
+            .filter(|&v| counts[v as usize] > 0)
+            .map(|v| (counts[v as usize], v))
+            .collect();
+        groups.sort();
+        groups.reverse();
+        let mut values: Vec<u8> = groups.iter().map(|&(_, v)| v).collect();
+
+        let is_straight_type =
+            matches!(self.hand_type, HandType::Straight | HandType::StraightFlush);
+        if is_straight_type && values == [ACE_VALUE, 5, 4, 3, 2] {
+            values.remove(0);
+            values.push(1);
+        }
+        values
+    }
+
+    //Cards in the order they decide a tie: the biggest group first, then kickers
+    //high to low. A wheel's ace goes last.
+    pub fn ranked_cards(&self) -> Vec<PlayingCard> {
+        let mut cards = Vec::new();
+        for value in self.tiebreak_values() {
+            let value = if value == 1 { ACE_VALUE } else { value };
+            cards.extend(
+                self.get_hand()
+                    .iter()
+                    .filter(|c| c.numeric_value() == value),
+            );
+        }
+        cards
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
+// Category and every kicker are packed into the HandRank, so one integer
+// comparison settles any two hands.
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        if self.hand_value().cmp(&other.hand_value()) == Ordering::Equal {
-            Hand::compare_equal_hands(self, other.clone())
-        } else {
-            self.hand_value().cmp(&other.hand_value())
-        }
+        self.rank().cmp(&other.rank())
     }
 }
 
@@ -470,10 +373,41 @@ impl fmt::Display for Hand {
     }
 }
 
+//Parses whitespace and/or comma separated cards, eg. "As Kd Tc" or the
+//"♣A , ♣K , " form Display writes.
+impl FromStr for Hand {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Hand, ParseCardError> {
+        let cards = s
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty())
+            .map(|token| token.parse::<PlayingCard>())
+            .collect::<Result<Vec<PlayingCard>, ParseCardError>>()?;
+        if cards.is_empty() {
+            return Err(ParseCardError::Empty);
+        }
+        if let Some(card) = first_duplicate(&cards) {
+            return Err(ParseCardError::DuplicateCard(card));
+        }
+        Ok(Hand::build(cards))
+    }
+}
+
+// First card that shows up a second time, if any.
+fn first_duplicate(cards: &[PlayingCard]) -> Option<PlayingCard> {
+    cards
+        .iter()
+        .enumerate()
+        .find(|(i, card)| cards[..*i].contains(card))
+        .map(|(_, &card)| card)
+}
+
 //PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
 #[cfg(test)]
 mod test {
     use super::*;
+    use card::Value;
 
     #[test]
     fn hand_match_test() {
@@ -484,7 +418,8 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Queen),
             PlayingCard::new(Suit::Club, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(royal_flush_hand.hand[0].get_value(), Value::Ace);
         assert_eq!(royal_flush_hand.hand[0].numeric_value(), 14);
         assert_eq!(royal_flush_hand.hand_type, HandType::RoyalFlush);
@@ -495,25 +430,28 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Ten),
             PlayingCard::new(Suit::Club, Value::Nine),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(straight_flush_hand.hand_type, HandType::StraightFlush);
         // Test Four Kind
         let four_of_a_kind_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
+            PlayingCard::new(Suit::Heart, Value::Ace),
+            PlayingCard::new(Suit::Spade, Value::Ace),
             PlayingCard::new(Suit::Diamond, Value::Ace),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(four_of_a_kind_hand.hand_type, HandType::FourKind);
         //Full House
         let full_house_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
+            PlayingCard::new(Suit::Diamond, Value::Ace),
+            PlayingCard::new(Suit::Heart, Value::Ace),
             PlayingCard::new(Suit::Diamond, Value::Ten),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(full_house_hand.hand_type, HandType::FullHouse);
 
         // Test Straight
@@ -523,7 +461,8 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Queen),
             PlayingCard::new(Suit::Club, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(straight_hand.hand_type, HandType::Straight);
 
         //Test 5-Ace Straight
@@ -533,26 +472,29 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Four),
             PlayingCard::new(Suit::Club, Value::Three),
             PlayingCard::new(Suit::Club, Value::Two),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(straight_two_hand.hand_type, HandType::Straight);
 
         // Test FLush
         let flush_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
+            PlayingCard::new(Suit::Club, Value::Eight),
             PlayingCard::new(Suit::Club, Value::Queen),
             PlayingCard::new(Suit::Club, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(flush_hand.hand_type, HandType::Flush);
         // Test Three Kind
         let three_kind_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::Ace),
             PlayingCard::new(Suit::Diamond, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
+            PlayingCard::new(Suit::Heart, Value::Ace),
             PlayingCard::new(Suit::Diamond, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(three_kind_hand.hand_type, HandType::ThreeKind);
         // Test Two Pair
         let two_pair_hand = Hand::from(vec![
@@ -561,7 +503,8 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Jack),
             PlayingCard::new(Suit::Diamond, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(two_pair_hand.hand_type, HandType::TwoPair);
         // Test Pair
         let pair_hand = Hand::from(vec![
@@ -570,7 +513,8 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Jack),
             PlayingCard::new(Suit::Diamond, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Nine),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(pair_hand.hand_type, HandType::Pair);
         // Test High Card
         let high_card_hand = Hand::from(vec![
@@ -579,7 +523,8 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Three),
             PlayingCard::new(Suit::Diamond, Value::Two),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(high_card_hand.hand_type, HandType::HighCard);
     }
 
@@ -592,7 +537,8 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Queen),
             PlayingCard::new(Suit::Club, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
 
         let other_royal_flush_hand = Hand::from(vec![
             PlayingCard::new(Suit::Diamond, Value::Ace),
@@ -600,7 +546,8 @@ mod test {
             PlayingCard::new(Suit::Diamond, Value::Queen),
             PlayingCard::new(Suit::Diamond, Value::Jack),
             PlayingCard::new(Suit::Diamond, Value::Ten),
-        ]);
+        ])
+        .unwrap();
 
         assert_eq!(
             royal_flush_hand.cmp(&other_royal_flush_hand),
@@ -613,7 +560,8 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Ten),
             PlayingCard::new(Suit::Club, Value::Nine),
-        ]);
+        ])
+        .unwrap();
 
         let other_straight_flush_hand = Hand::from(vec![
             PlayingCard::new(Suit::Diamond, Value::Ten),
@@ -621,7 +569,8 @@ mod test {
             PlayingCard::new(Suit::Diamond, Value::Eight),
             PlayingCard::new(Suit::Diamond, Value::Seven),
             PlayingCard::new(Suit::Diamond, Value::Six),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(straight_flush_hand.hand_type, HandType::StraightFlush);
         assert_eq!(other_straight_flush_hand.hand_type, HandType::StraightFlush);
 
@@ -632,36 +581,40 @@ mod test {
         //Compare four kind
         let four_kind_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
+            PlayingCard::new(Suit::Heart, Value::Ace),
+            PlayingCard::new(Suit::Spade, Value::Ace),
             PlayingCard::new(Suit::Diamond, Value::Ace),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
 
         let other_four_kind_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::Ace),
             PlayingCard::new(Suit::Club, Value::Ten),
-            PlayingCard::new(Suit::Club, Value::Ten),
+            PlayingCard::new(Suit::Heart, Value::Ten),
             PlayingCard::new(Suit::Diamond, Value::Ten),
-            PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+            PlayingCard::new(Suit::Spade, Value::Ten),
+        ])
+        .unwrap();
         assert_eq!(four_kind_hand.cmp(&other_four_kind_hand), Ordering::Greater);
         //Compare full house
         let full_house_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
+            PlayingCard::new(Suit::Diamond, Value::Ace),
+            PlayingCard::new(Suit::Heart, Value::Ace),
             PlayingCard::new(Suit::Diamond, Value::Ten),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
 
         let other_full_house_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::Nine),
-            PlayingCard::new(Suit::Club, Value::Nine),
-            PlayingCard::new(Suit::Club, Value::Nine),
+            PlayingCard::new(Suit::Diamond, Value::Nine),
+            PlayingCard::new(Suit::Heart, Value::Nine),
             PlayingCard::new(Suit::Diamond, Value::Ten),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(
             full_house_hand.cmp(&other_full_house_hand),
             Ordering::Greater
@@ -673,7 +626,8 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Queen),
             PlayingCard::new(Suit::Club, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Nine),
-        ]);
+        ])
+        .unwrap();
 
         let other_flush_hand = Hand::from(vec![
             PlayingCard::new(Suit::Diamond, Value::Ten),
@@ -681,7 +635,8 @@ mod test {
             PlayingCard::new(Suit::Diamond, Value::Eight),
             PlayingCard::new(Suit::Diamond, Value::Seven),
             PlayingCard::new(Suit::Diamond, Value::Five),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(flush_hand.cmp(&other_flush_hand), Ordering::Greater);
 
         //Compare straight
@@ -691,7 +646,8 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Queen),
             PlayingCard::new(Suit::Club, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
 
         let other_straight_hand = Hand::from(vec![
             PlayingCard::new(Suit::Diamond, Value::Ten),
@@ -699,25 +655,28 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Eight),
             PlayingCard::new(Suit::Diamond, Value::Seven),
             PlayingCard::new(Suit::Diamond, Value::Six),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(straight_hand.cmp(&other_straight_hand), Ordering::Greater);
 
         //Compare three kind
         let three_kind_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
+            PlayingCard::new(Suit::Diamond, Value::Ace),
+            PlayingCard::new(Suit::Heart, Value::Ace),
             PlayingCard::new(Suit::Diamond, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
 
         let other_three_kind_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::Ace),
             PlayingCard::new(Suit::Club, Value::Ten),
-            PlayingCard::new(Suit::Club, Value::Ten),
+            PlayingCard::new(Suit::Heart, Value::Ten),
             PlayingCard::new(Suit::Diamond, Value::Ten),
             PlayingCard::new(Suit::Club, Value::Nine),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(
             three_kind_hand.cmp(&other_three_kind_hand),
             Ordering::Greater
@@ -726,11 +685,12 @@ mod test {
         //Compare pair
         let pair_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::Ace),
-            PlayingCard::new(Suit::Club, Value::Ace),
+            PlayingCard::new(Suit::Diamond, Value::Ace),
             PlayingCard::new(Suit::Club, Value::Queen),
             PlayingCard::new(Suit::Diamond, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Ten),
-        ]);
+        ])
+        .unwrap();
 
         let other_pair_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::Ace),
@@ -738,7 +698,8 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Ten),
             PlayingCard::new(Suit::Diamond, Value::Ten),
             PlayingCard::new(Suit::Club, Value::Nine),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(pair_hand.cmp(&other_pair_hand), Ordering::Greater);
         //Compare high card
         let high_card_hand = Hand::from(vec![
@@ -747,7 +708,8 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Queen),
             PlayingCard::new(Suit::Diamond, Value::Jack),
             PlayingCard::new(Suit::Club, Value::Nine),
-        ]);
+        ])
+        .unwrap();
 
         let other_high_card_hand = Hand::from(vec![
             PlayingCard::new(Suit::Club, Value::King),
@@ -755,7 +717,113 @@ mod test {
             PlayingCard::new(Suit::Club, Value::Ten),
             PlayingCard::new(Suit::Diamond, Value::Nine),
             PlayingCard::new(Suit::Club, Value::Eight),
-        ]);
+        ])
+        .unwrap();
         assert_eq!(high_card_hand.cmp(&other_high_card_hand), Ordering::Greater);
     }
+
+    #[test]
+    fn parse_hand_test() {
+        let hand: Hand = "As Ks Qs Js Ts".parse().unwrap();
+        assert_eq!(hand.hand_type, HandType::RoyalFlush);
+        assert_eq!(hand.to_string().parse::<Hand>().unwrap(), hand);
+
+        let partial: Hand = "As,Kd, 10c".parse().unwrap();
+        assert_eq!(partial.get_hand().len(), 3);
+        assert_eq!(partial.hand_type, HandType::None);
+
+        // A joker isn't a value yet, so this isn't a straight.
+        let joker: Hand = "Ah Kd Qc Jh Jks".parse().unwrap();
+        assert_eq!(joker.hand_type, HandType::None);
+
+        assert_eq!("  ".parse::<Hand>(), Err(ParseCardError::Empty));
+        let ace: PlayingCard = "As".parse().unwrap();
+        assert_eq!(
+            "As As Kd Qc Jh".parse::<Hand>(),
+            Err(ParseCardError::DuplicateCard(ace))
+        );
+        assert_eq!(
+            Hand::from(vec![ace, "Kd".parse().unwrap(), ace]),
+            Err(CardError::DuplicateCard(ace))
+        );
+        assert_eq!(
+            "As Kz".parse::<Hand>(),
+            Err(ParseCardError::InvalidSuit("z".to_string()))
+        );
+    }
+
+    #[test]
+    fn kicker_comparison_test() {
+        // Same pair, decided by the last kicker.
+        let pair_hand = Hand::from(vec![
+            PlayingCard::new(Suit::Club, Value::Nine),
+            PlayingCard::new(Suit::Diamond, Value::Nine),
+            PlayingCard::new(Suit::Club, Value::Ace),
+            PlayingCard::new(Suit::Diamond, Value::Seven),
+            PlayingCard::new(Suit::Club, Value::Four),
+        ])
+        .unwrap();
+        let other_pair_hand = Hand::from(vec![
+            PlayingCard::new(Suit::Heart, Value::Nine),
+            PlayingCard::new(Suit::Spade, Value::Nine),
+            PlayingCard::new(Suit::Heart, Value::Ace),
+            PlayingCard::new(Suit::Spade, Value::Seven),
+            PlayingCard::new(Suit::Heart, Value::Three),
+        ])
+        .unwrap();
+        assert_eq!(pair_hand.cmp(&other_pair_hand), Ordering::Greater);
+        assert!(pair_hand > other_pair_hand);
+
+        // Bigger trips win a full house even when the pair is smaller.
+        let full_house_hand = Hand::from(vec![
+            PlayingCard::new(Suit::Club, Value::Ten),
+            PlayingCard::new(Suit::Diamond, Value::Ten),
+            PlayingCard::new(Suit::Heart, Value::Ten),
+            PlayingCard::new(Suit::Diamond, Value::Two),
+            PlayingCard::new(Suit::Club, Value::Two),
+        ])
+        .unwrap();
+        let other_full_house_hand = Hand::from(vec![
+            PlayingCard::new(Suit::Club, Value::Nine),
+            PlayingCard::new(Suit::Diamond, Value::Nine),
+            PlayingCard::new(Suit::Heart, Value::Nine),
+            PlayingCard::new(Suit::Diamond, Value::Ace),
+            PlayingCard::new(Suit::Club, Value::Ace),
+        ])
+        .unwrap();
+        assert_eq!(
+            full_house_hand.cmp(&other_full_house_hand),
+            Ordering::Greater
+        );
+
+        // The wheel is the lowest straight.
+        let wheel_hand = Hand::from(vec![
+            PlayingCard::new(Suit::Club, Value::Ace),
+            PlayingCard::new(Suit::Diamond, Value::Two),
+            PlayingCard::new(Suit::Heart, Value::Three),
+            PlayingCard::new(Suit::Diamond, Value::Four),
+            PlayingCard::new(Suit::Club, Value::Five),
+        ])
+        .unwrap();
+        let six_high_hand = Hand::from(vec![
+            PlayingCard::new(Suit::Club, Value::Six),
+            PlayingCard::new(Suit::Diamond, Value::Two),
+            PlayingCard::new(Suit::Heart, Value::Three),
+            PlayingCard::new(Suit::Diamond, Value::Four),
+            PlayingCard::new(Suit::Club, Value::Five),
+        ])
+        .unwrap();
+        assert_eq!(wheel_hand.cmp(&six_high_hand), Ordering::Less);
+
+        // Only a full kicker match is a tie.
+        let same_pair_hand = Hand::from(vec![
+            PlayingCard::new(Suit::Heart, Value::Nine),
+            PlayingCard::new(Suit::Spade, Value::Nine),
+            PlayingCard::new(Suit::Heart, Value::Ace),
+            PlayingCard::new(Suit::Spade, Value::Seven),
+            PlayingCard::new(Suit::Heart, Value::Four),
+        ])
+        .unwrap();
+        assert_eq!(pair_hand.cmp(&same_pair_hand), Ordering::Equal);
+    }
 }
@@ -1,5 +1,8 @@
 use core::fmt;
 use std::cmp::Ordering;
+use std::str::FromStr;
+
+use super::error::CardError;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Suit {
@@ -9,6 +12,45 @@ pub enum Suit {
     Club,
 }
 
+///Order suits are ranked in where suits break ties (eg. the stud bring-in).
+///Lowest suit first. The derived `Ord` on Suit is only for sorting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuitRanking {
+    order: [Suit; 4],
+}
+
+impl Default for SuitRanking {
+    //Bridge order: clubs, diamonds, hearts, spades.
+    fn default() -> SuitRanking {
+        SuitRanking {
+            order: [Suit::Club, Suit::Diamond, Suit::Heart, Suit::Spade],
+        }
+    }
+}
+
+impl SuitRanking {
+    //Every suit has to show up exactly once.
+    pub fn new(order: [Suit; 4]) -> Result<SuitRanking, CardError> {
+        for (i, suit) in order.iter().enumerate() {
+            if order[..i].contains(suit) {
+                return Err(CardError::DuplicateSuit(*suit));
+            }
+        }
+        Ok(SuitRanking { order })
+    }
+    //Just a getter.
+    pub fn get_order(&self) -> &[Suit; 4] {
+        &self.order
+    }
+    //0 for the lowest suit up to 3 for the highest.
+    pub fn rank(&self, suit: Suit) -> usize {
+        self.order
+            .iter()
+            .position(|&s| s == suit)
+            .expect("A SuitRanking holds every suit.")
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Value {
     Ace,
@@ -24,9 +66,49 @@ pub enum Value {
     Four,
     Three,
     Two,
+    //Not a real value, a joker only gets one once it's played as a wild card.
+    Joker,
+}
+
+impl Suit {
+    //Every suit, in declaration order.
+    pub const ALL: [Suit; 4] = [Suit::Heart, Suit::Diamond, Suit::Spade, Suit::Club];
+
+    //Position of the suit in Suit::ALL.
+    pub fn index(&self) -> usize {
+        match self {
+            Suit::Heart => 0,
+            Suit::Diamond => 1,
+            Suit::Spade => 2,
+            Suit::Club => 3,
+        }
+    }
 }
 
 impl Value {
+    //Every value, lowest to highest. Jokers aren't included.
+    pub const ALL: [Value; 13] = [
+        Value::Two,
+        Value::Three,
+        Value::Four,
+        Value::Five,
+        Value::Six,
+        Value::Seven,
+        Value::Eight,
+        Value::Nine,
+        Value::Ten,
+        Value::Jack,
+        Value::Queen,
+        Value::King,
+        Value::Ace,
+    ];
+
+    //Position of the value in Value::ALL, 0 for a two up to 12 for an ace.
+    //A joker comes out as 13, just past the ace.
+    pub fn index(&self) -> usize {
+        self.numeric_value() as usize - 2
+    }
+
     pub fn numeric_value(&self) -> u8 {
         match self {
             Value::Ace => 14,
@@ -42,6 +124,35 @@ impl Value {
             Value::Four => 4,
             Value::Three => 3,
             Value::Two => 2,
+            Value::Joker => 15,
+        }
+    }
+
+    //Written out name, eg. "Ace" or "Ten".
+    pub fn name(&self) -> &'static str {
+        match self {
+            Value::Ace => "Ace",
+            Value::King => "King",
+            Value::Queen => "Queen",
+            Value::Jack => "Jack",
+            Value::Ten => "Ten",
+            Value::Nine => "Nine",
+            Value::Eight => "Eight",
+            Value::Seven => "Seven",
+            Value::Six => "Six",
+            Value::Five => "Five",
+            Value::Four => "Four",
+            Value::Three => "Three",
+            Value::Two => "Two",
+            Value::Joker => "Joker",
+        }
+    }
+
+    //Name for more than one, eg. "Aces" or "Sixes".
+    pub fn plural(&self) -> String {
+        match self {
+            Value::Six => "Sixes".to_string(),
+            _ => format!("{}s", self.name()),
         }
     }
 }
@@ -56,6 +167,13 @@ impl PlayingCard {
     pub fn new(s: Suit, v: Value) -> PlayingCard {
         PlayingCard { suit: s, value: v }
     }
+    //Jokers keep a suit only to tell them apart (eg. the red and the black joker).
+    pub fn joker(s: Suit) -> PlayingCard {
+        PlayingCard::new(s, Value::Joker)
+    }
+    pub fn is_joker(&self) -> bool {
+        self.value == Value::Joker
+    }
     //Standard getter.
     pub fn get_value(&self) -> Value {
         self.value
@@ -68,6 +186,27 @@ impl PlayingCard {
     pub fn numeric_value(&self) -> u8 {
         self.value.numeric_value()
     }
+    //Unique 0-51 position of the card, grouped by suit. Jokers come after, 52-55.
+    pub fn index(&self) -> usize {
+        if self.is_joker() {
+            return Suit::ALL.len() * Value::ALL.len() + self.suit.index();
+        }
+        self.suit.index() * Value::ALL.len() + self.value.index()
+    }
+    //Inverse of index(). None for anything past 55.
+    pub fn from_index(index: usize) -> Option<PlayingCard> {
+        let standard = Suit::ALL.len() * Value::ALL.len();
+        if index >= standard + Suit::ALL.len() {
+            return None;
+        }
+        if index >= standard {
+            return Some(PlayingCard::joker(Suit::ALL[index - standard]));
+        }
+        Some(PlayingCard::new(
+            Suit::ALL[index / Value::ALL.len()],
+            Value::ALL[index % Value::ALL.len()],
+        ))
+    }
 }
 
 //Determines display syntax for card
@@ -106,14 +245,100 @@ impl fmt::Display for Value {
             Value::Four => write!(f, "4"),
             Value::Three => write!(f, "3"),
             Value::Two => write!(f, "2"),
+            Value::Joker => write!(f, "★"),
+        }
+    }
+}
+
+///Reasons a card, suit, value or hand failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCardError {
+    Empty,
+    InvalidSuit(String),
+    InvalidValue(String),
+    DuplicateCard(PlayingCard),
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseCardError::Empty => write!(f, "Nothing to parse"),
+            ParseCardError::InvalidSuit(s) => write!(f, "'{}' is not a suit", s),
+            ParseCardError::InvalidValue(s) => write!(f, "'{}' is not a card value", s),
+            ParseCardError::DuplicateCard(card) => write!(f, "{} shows up more than once", card),
+        }
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
+//Accepts letters (h, d, s, c - any case) or the suit glyphs used by Display.
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Suit, ParseCardError> {
+        match s {
+            "" => Err(ParseCardError::Empty),
+            "h" | "H" | "♥" | "♡" => Ok(Suit::Heart),
+            "d" | "D" | "♦" | "♢" => Ok(Suit::Diamond),
+            "s" | "S" | "♠" | "♤" => Ok(Suit::Spade),
+            "c" | "C" | "♣" | "♧" => Ok(Suit::Club),
+            _ => Err(ParseCardError::InvalidSuit(s.to_string())),
+        }
+    }
+}
+
+//Accepts A K Q J T (any case), 10, and 9 down to 2. Jokers are "Jk" or "★".
+impl FromStr for Value {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Value, ParseCardError> {
+        match s.to_uppercase().as_str() {
+            "" => Err(ParseCardError::Empty),
+            "A" => Ok(Value::Ace),
+            "K" => Ok(Value::King),
+            "Q" => Ok(Value::Queen),
+            "J" => Ok(Value::Jack),
+            "T" | "10" => Ok(Value::Ten),
+            "9" => Ok(Value::Nine),
+            "8" => Ok(Value::Eight),
+            "7" => Ok(Value::Seven),
+            "6" => Ok(Value::Six),
+            "5" => Ok(Value::Five),
+            "4" => Ok(Value::Four),
+            "3" => Ok(Value::Three),
+            "2" => Ok(Value::Two),
+            "JK" | "★" => Ok(Value::Joker),
+            _ => Err(ParseCardError::InvalidValue(s.to_string())),
         }
     }
 }
 
+//Accepts value-then-suit ("As", "10h", "K♦") as well as the suit-then-value
+//form Display writes ("♣A"), so printed cards parse back.
+impl FromStr for PlayingCard {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<PlayingCard, ParseCardError> {
+        let s = s.trim();
+        let first = s.chars().next().ok_or(ParseCardError::Empty)?;
+        if "♥♡♦♢♠♤♣♧".contains(first) {
+            let (suit, value) = s.split_at(first.len_utf8());
+            return Ok(PlayingCard::new(suit.parse()?, value.parse()?));
+        }
+        let last = s.chars().next_back().ok_or(ParseCardError::Empty)?;
+        let (value, suit) = s.split_at(s.len() - last.len_utf8());
+        if value.is_empty() {
+            return Err(ParseCardError::InvalidValue(s.to_string()));
+        }
+        Ok(PlayingCard::new(suit.parse()?, value.parse()?))
+    }
+}
+
 //Unsure if this is necessary or if I can just derive this. Will test.
 impl PartialOrd for Value {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.numeric_value().cmp(&other.numeric_value()))
+        Some(self.cmp(other))
     }
 }
 
@@ -128,7 +353,7 @@ impl Ord for Value {
 
 impl PartialOrd for PlayingCard {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.numeric_value().cmp(&other.numeric_value()))
+        Some(self.cmp(other))
     }
 }
 
@@ -141,4 +366,79 @@ impl Ord for PlayingCard {
 
 //PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(
+            "As".parse::<PlayingCard>(),
+            Ok(PlayingCard::new(Suit::Spade, Value::Ace))
+        );
+        assert_eq!(
+            "10h".parse::<PlayingCard>(),
+            Ok(PlayingCard::new(Suit::Heart, Value::Ten))
+        );
+        assert_eq!(
+            "tc".parse::<PlayingCard>(),
+            Ok(PlayingCard::new(Suit::Club, Value::Ten))
+        );
+        assert_eq!(
+            "K♦".parse::<PlayingCard>(),
+            Ok(PlayingCard::new(Suit::Diamond, Value::King))
+        );
+        assert_eq!(
+            "♣2".parse::<PlayingCard>(),
+            Ok(PlayingCard::new(Suit::Club, Value::Two))
+        );
+
+        assert_eq!("".parse::<PlayingCard>(), Err(ParseCardError::Empty));
+        assert_eq!(
+            "s".parse::<PlayingCard>(),
+            Err(ParseCardError::InvalidValue("s".to_string()))
+        );
+        assert_eq!(
+            "Ax".parse::<PlayingCard>(),
+            Err(ParseCardError::InvalidSuit("x".to_string()))
+        );
+        assert_eq!(
+            "1h".parse::<PlayingCard>(),
+            Err(ParseCardError::InvalidValue("1".to_string()))
+        );
+    }
+
+    #[test]
+    fn display_round_trip_test() {
+        for &suit in &Suit::ALL {
+            assert_eq!(suit.to_string().parse::<Suit>(), Ok(suit));
+            for &value in &Value::ALL {
+                assert_eq!(value.to_string().parse::<Value>(), Ok(value));
+                let card = PlayingCard::new(suit, value);
+                assert_eq!(card.to_string().parse::<PlayingCard>(), Ok(card));
+            }
+        }
+        for &suit in &Suit::ALL {
+            let joker = PlayingCard::joker(suit);
+            assert_eq!(joker.to_string().parse::<PlayingCard>(), Ok(joker));
+            assert_eq!(PlayingCard::from_index(joker.index()), Some(joker));
+        }
+        assert_eq!(
+            "Jks".parse::<PlayingCard>(),
+            Ok(PlayingCard::joker(Suit::Spade))
+        );
+        assert_eq!(PlayingCard::from_index(56), None);
+    }
+
+    #[test]
+    fn suit_ranking_test() {
+        let ranking =
+            SuitRanking::new([Suit::Spade, Suit::Heart, Suit::Diamond, Suit::Club]).unwrap();
+        assert_eq!(ranking.rank(Suit::Spade), 0);
+        assert_eq!(ranking.rank(Suit::Club), 3);
+        assert_eq!(SuitRanking::default().rank(Suit::Spade), 3);
+        assert_eq!(
+            SuitRanking::new([Suit::Spade, Suit::Heart, Suit::Spade, Suit::Club]),
+            Err(CardError::DuplicateSuit(Suit::Spade))
+        );
+    }
+}
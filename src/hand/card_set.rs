@@ -0,0 +1,271 @@
+use core::fmt;
+use std::ops::{BitAnd, BitOr, Not, Sub};
+
+use super::Hand;
+use super::card::PlayingCard;
+
+// CONSTANTS
+const DECK_SIZE: usize = 52;
+const FULL_DECK_BITS: u64 = (1 << DECK_SIZE) - 1;
+
+///Set of cards backed by a single u64, one bit per card (see PlayingCard::index).
+///Union, intersection, membership and counting are all single bit operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    // ---------------------------
+    // Constructors
+    // ---------------------------
+
+    //Empty set.
+    pub fn new() -> CardSet {
+        CardSet(0)
+    }
+
+    //All 52 cards.
+    pub fn full_deck() -> CardSet {
+        CardSet(FULL_DECK_BITS)
+    }
+
+    //Builds a set straight from its bits. Anything past bit 51 is dropped.
+    pub fn from_bits(bits: u64) -> CardSet {
+        CardSet(bits & FULL_DECK_BITS)
+    }
+
+    // ---------------------------
+    // Queries
+    // ---------------------------
+
+    //Just a getter.
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    pub fn contains(&self, card: PlayingCard) -> bool {
+        self.0 & Self::bit(card) != 0
+    }
+
+    pub fn count(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    //Cards in both sets.
+    pub fn intersection(&self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    //Cards in either set.
+    pub fn union(&self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    //Cards in this set that aren't in the other.
+    pub fn difference(&self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    //Every card of a full deck that isn't in this set.
+    pub fn complement(&self) -> CardSet {
+        CardSet(!self.0 & FULL_DECK_BITS)
+    }
+
+    //Iterates cards from the lowest index up.
+    pub fn iter(&self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    // ---------------------------
+    // Card Operations
+    // ---------------------------
+
+    //Adds a card. Returns false if it was already in the set.
+    pub fn insert(&mut self, card: PlayingCard) -> bool {
+        let was_present = self.contains(card);
+        self.0 |= Self::bit(card);
+        !was_present
+    }
+
+    //Removes a card. Returns false if it wasn't in the set.
+    pub fn remove(&mut self, card: PlayingCard) -> bool {
+        let was_present = self.contains(card);
+        self.0 &= !Self::bit(card);
+        was_present
+    }
+
+    // ---------------------------
+    // Conversions
+    // ---------------------------
+
+    pub fn to_vec(&self) -> Vec<PlayingCard> {
+        self.iter().collect()
+    }
+
+    //Hand holding exactly the cards of the set.
+    pub fn to_hand(&self) -> Hand {
+        Hand::from(self.to_vec()).expect("A CardSet holds each card at most once.")
+    }
+
+    fn bit(card: PlayingCard) -> u64 {
+        1 << card.index()
+    }
+}
+
+///Iterator over the cards of a CardSet.
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = PlayingCard;
+
+    fn next(&mut self) -> Option<PlayingCard> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros() as usize;
+        // Clears the lowest set bit.
+        self.0 &= self.0 - 1;
+        PlayingCard::from_index(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.0.count_ones() as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = PlayingCard;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> CardSetIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<PlayingCard> for CardSet {
+    fn from_iter<I: IntoIterator<Item = PlayingCard>>(iter: I) -> CardSet {
+        let mut set = CardSet::new();
+        for card in iter {
+            set.insert(card);
+        }
+        set
+    }
+}
+
+impl From<PlayingCard> for CardSet {
+    fn from(card: PlayingCard) -> CardSet {
+        CardSet(Self::bit(card))
+    }
+}
+
+impl From<&Hand> for CardSet {
+    fn from(hand: &Hand) -> CardSet {
+        hand.get_hand().iter().copied().collect()
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    fn bitor(self, other: CardSet) -> CardSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    fn bitand(self, other: CardSet) -> CardSet {
+        self.intersection(other)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+
+    fn sub(self, other: CardSet) -> CardSet {
+        self.difference(other)
+    }
+}
+
+impl Not for CardSet {
+    type Output = CardSet;
+
+    fn not(self) -> CardSet {
+        self.complement()
+    }
+}
+
+impl fmt::Display for CardSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for card in self.iter() {
+            write!(f, "{} , ", card)?;
+        }
+        Ok(())
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::deck::Deck;
+    use crate::hand::card::{Suit, Value};
+
+    #[test]
+    fn set_operations_test() {
+        let ace = PlayingCard::new(Suit::Club, Value::Ace);
+        let king = PlayingCard::new(Suit::Heart, Value::King);
+        let two = PlayingCard::new(Suit::Spade, Value::Two);
+
+        let mut set = CardSet::new();
+        assert!(set.insert(ace));
+        assert!(!set.insert(ace));
+        set.insert(king);
+        assert_eq!(set.count(), 2);
+        assert!(set.contains(king));
+        assert!(!set.contains(two));
+
+        let other: CardSet = [king, two].into_iter().collect();
+        assert_eq!((set & other).to_vec(), vec![king]);
+        assert_eq!((set | other).count(), 3);
+        assert_eq!((set - other).to_vec(), vec![ace]);
+        assert_eq!((!set).count(), 50);
+
+        assert!(set.remove(ace));
+        assert!(!set.remove(ace));
+        assert_eq!(set, CardSet::from(king));
+    }
+
+    #[test]
+    fn conversion_test() {
+        let full = CardSet::full_deck();
+        assert_eq!(full.count(), 52);
+        assert_eq!(full.iter().count(), 52);
+        for (i, card) in full.iter().enumerate() {
+            assert_eq!(card.index(), i);
+        }
+
+        let deck: CardSet = Deck::seeded(1).get_cards().into_iter().collect();
+        assert_eq!(deck, full);
+
+        let hand = Hand::from(vec![
+            PlayingCard::new(Suit::Club, Value::Ace),
+            PlayingCard::new(Suit::Diamond, Value::Ace),
+            PlayingCard::new(Suit::Club, Value::Jack),
+            PlayingCard::new(Suit::Diamond, Value::Jack),
+            PlayingCard::new(Suit::Club, Value::Ten),
+        ])
+        .unwrap();
+        let set = CardSet::from(&hand);
+        assert_eq!(set.count(), 5);
+        // Same cards and type, although ties in value may sort differently.
+        let back = set.to_hand();
+        assert_eq!(back.get_hand_type(), hand.get_hand_type());
+        assert_eq!(CardSet::from(&back), set);
+    }
+}
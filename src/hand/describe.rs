@@ -0,0 +1,224 @@
+use std::cmp::Ordering;
+
+use super::Hand;
+use super::card::Value;
+use super::hand_type::HandType;
+
+// Ways to refer to the nth card of a high card hand or a flush.
+const ORDINALS: [&str; 5] = ["top", "second", "third", "fourth", "fifth"];
+
+//Reads a hand out the way a dealer would, eg. "Full house, Aces full of Tens",
+//"Two pair, Jacks and Fours with a King kicker" or "Ace-high straight (Broadway)".
+pub fn describe(hand: &Hand) -> String {
+    let values = ranked_values(hand);
+    let Some(&top) = values.first() else {
+        return "No cards".to_string();
+    };
+    let rest = &values[1..];
+    match hand.get_hand_type() {
+        HandType::FiveKind => format!("Five of a kind, {}", top.plural()),
+        HandType::RoyalFlush => "Royal flush".to_string(),
+        HandType::StraightFlush if top == Value::Five => {
+            "Five-high straight flush (Steel Wheel)".to_string()
+        }
+        HandType::StraightFlush => format!("{}-high straight flush", top.name()),
+        HandType::FourKind => format!("Four of a kind, {}{}", top.plural(), kickers(rest)),
+        HandType::FullHouse => match rest.first() {
+            Some(pair) => format!("Full house, {} full of {}", top.plural(), pair.plural()),
+            None => format!("Full house, {} full", top.plural()),
+        },
+        HandType::Flush => format!("{}-high flush{}", top.name(), with(rest)),
+        HandType::Straight if top == Value::Ace => "Ace-high straight (Broadway)".to_string(),
+        HandType::Straight if top == Value::Five => "Five-high straight (the Wheel)".to_string(),
+        HandType::Straight => format!("{}-high straight", top.name()),
+        HandType::ThreeKind => format!("Three of a kind, {}{}", top.plural(), kickers(rest)),
+        HandType::TwoPair => match rest.split_first() {
+            Some((second, rest)) => format!(
+                "Two pair, {} and {}{}",
+                top.plural(),
+                second.plural(),
+                kickers(rest)
+            ),
+            None => format!("Two pair, {}", top.plural()),
+        },
+        HandType::Pair => format!("Pair of {}{}", top.plural(), kickers(rest)),
+        HandType::HighCard => format!("{} high{}", top.name(), with(rest)),
+        HandType::None => {
+            let names: Vec<&str> = values.iter().map(|v| v.name()).collect();
+            list(&names)
+        }
+    }
+}
+
+//Says why one hand beats (or ties) another: on hand type when they differ,
+//otherwise which card settled it, eg. "... on the kicker, King over Nine."
+//Either order works, the better hand is always read first.
+pub fn explain(first: &Hand, second: &Hand) -> String {
+    let (winner, loser) = match first.rank().cmp(&second.rank()) {
+        Ordering::Less => (second, first),
+        _ => (first, second),
+    };
+    let (won, lost) = (describe(winner), describe(loser));
+    if winner.rank() == loser.rank() {
+        return format!("{} ties {}, every card matches.", won, lost);
+    }
+    if winner.get_hand_type() != loser.get_hand_type() {
+        return format!("{} beats {} on hand type.", won, lost);
+    }
+    let decider = ranked_values(winner)
+        .into_iter()
+        .zip(ranked_values(loser))
+        .enumerate()
+        .find(|(_, (w, l))| w != l);
+    match decider {
+        Some((index, (w, l))) => format!(
+            "{} beats {} on the {}, {} over {}.",
+            won,
+            lost,
+            role(&winner.get_hand_type(), index),
+            w.name(),
+            l.name()
+        ),
+        None => format!("{} beats {}.", won, lost),
+    }
+}
+
+// Distinct values in the order they settle a tie (see Hand::ranked_cards).
+fn ranked_values(hand: &Hand) -> Vec<Value> {
+    let mut values: Vec<Value> = Vec::new();
+    for card in hand.ranked_cards() {
+        if values.last() != Some(&card.get_value()) {
+            values.push(card.get_value());
+        }
+    }
+    values
+}
+
+// What the value at a position in ranked_values stands for.
+fn role(hand_type: &HandType, index: usize) -> String {
+    let role = match (hand_type, index) {
+        (HandType::HighCard | HandType::Flush, i) => {
+            return format!("{} card", ORDINALS.get(i).unwrap_or(&"last"));
+        }
+        (HandType::Straight | HandType::StraightFlush, _) => "top card",
+        (HandType::FiveKind, _) => "five of a kind",
+        (HandType::FourKind, 0) => "four of a kind",
+        (HandType::FullHouse | HandType::ThreeKind, 0) => "three of a kind",
+        (HandType::FullHouse, _) => "pair",
+        (HandType::TwoPair, 0) => "top pair",
+        (HandType::TwoPair, 1) => "bottom pair",
+        (HandType::Pair, 0) => "pair",
+        (HandType::None, _) => "cards",
+        _ => "kicker",
+    };
+    role.to_string()
+}
+
+// " with a King kicker" or " with Ace, Nine and Four kickers", nothing without kickers.
+fn kickers(values: &[Value]) -> String {
+    match values {
+        [] => String::new(),
+        [kicker] => format!(" with {} {} kicker", article(*kicker), kicker.name()),
+        _ => format!("{} kickers", with(values)),
+    }
+}
+
+// " with Jack, Nine, Six and Two", nothing if there's nothing to list.
+fn with(values: &[Value]) -> String {
+    if values.is_empty() {
+        return String::new();
+    }
+    let names: Vec<&str> = values.iter().map(|v| v.name()).collect();
+    format!(" with {}", list(&names))
+}
+
+fn article(value: Value) -> &'static str {
+    match value {
+        Value::Ace | Value::Eight => "an",
+        _ => "a",
+    }
+}
+
+// "A", "A and B", "A, B and C".
+fn list(names: &[&str]) -> String {
+    match names.split_last() {
+        None => String::new(),
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn described(hand: &str) -> String {
+        describe(&hand.parse().unwrap())
+    }
+
+    fn explained(first: &str, second: &str) -> String {
+        explain(&first.parse().unwrap(), &second.parse().unwrap())
+    }
+
+    #[test]
+    fn describe_test() {
+        assert_eq!(described("As Ad Ah Ts Tc"), "Full house, Aces full of Tens");
+        assert_eq!(
+            described("Js Jd 4h 4s Kc"),
+            "Two pair, Jacks and Fours with a King kicker"
+        );
+        assert_eq!(described("As Kd Qc Jh Ts"), "Ace-high straight (Broadway)");
+        assert_eq!(
+            described("As 2d 3c 4h 5s"),
+            "Five-high straight (the Wheel)"
+        );
+        assert_eq!(described("9h 8h 7h 6h 5h"), "Nine-high straight flush");
+        assert_eq!(described("Ah Kh Qh Jh Th"), "Royal flush");
+        assert_eq!(
+            described("Kd Jd 9d 6d 2d"),
+            "King-high flush with Jack, Nine, Six and Two"
+        );
+        assert_eq!(
+            described("6c 6d Ah 9s 4c"),
+            "Pair of Sixes with Ace, Nine and Four kickers"
+        );
+        assert_eq!(
+            described("9c 9d 9h 9s 8c"),
+            "Four of a kind, Nines with an Eight kicker"
+        );
+        assert_eq!(
+            described("Ac Kd 9h 7s 4c"),
+            "Ace high with King, Nine, Seven and Four"
+        );
+        assert_eq!(described("Ac Kd"), "Ace and King");
+    }
+
+    #[test]
+    fn explain_test() {
+        assert_eq!(
+            explained("Js Jd 4h 4s 9c", "Jh Jc 4d 4c Kc"),
+            "Two pair, Jacks and Fours with a King kicker beats \
+             Two pair, Jacks and Fours with a Nine kicker on the kicker, King over Nine."
+        );
+        assert_eq!(
+            explained("Ks Kd Kh 2s 2c", "As Ad Ah 3s 3c"),
+            "Full house, Aces full of Threes beats Full house, Kings full of Twos \
+             on the three of a kind, Ace over King."
+        );
+        assert_eq!(
+            explained("Kd Jd 9d 6d 2d", "Ac Ad 5h 4s 3c"),
+            "King-high flush with Jack, Nine, Six and Two beats \
+             Pair of Aces with Five, Four and Three kickers on hand type."
+        );
+        assert_eq!(
+            explained("Ac Kd 9h 7s 4c", "Ad Kc 9s 6s 4d"),
+            "Ace high with King, Nine, Seven and Four beats \
+             Ace high with King, Nine, Six and Four on the fourth card, Seven over Six."
+        );
+        assert_eq!(
+            explained("As Kd Qc Jh Ts", "Ac Kh Qd Js Th"),
+            "Ace-high straight (Broadway) ties Ace-high straight (Broadway), every card matches."
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use core::fmt;
+
+use super::card::{PlayingCard, Suit};
+
+///Reasons a hand or deck operation can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardError {
+    HandFull,
+    IndexOutOfRange {
+        index: usize,
+        len: usize,
+    },
+    DeckEmpty,
+    DuplicateCard(PlayingCard),
+    MissingCard(PlayingCard),
+    CardCount {
+        count: usize,
+        min: usize,
+        max: usize,
+    },
+    HoleCardCount {
+        count: usize,
+        min: usize,
+        max: usize,
+    },
+    BoardCardCount {
+        count: usize,
+        min: usize,
+        max: usize,
+    },
+    WildCard(PlayingCard),
+    DuplicateSuit(Suit),
+    JokerCount {
+        count: usize,
+        max: usize,
+    },
+}
+
+impl fmt::Display for CardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CardError::HandFull => write!(f, "Hand is full"),
+            CardError::IndexOutOfRange { index, len } => {
+                write!(f, "Index {} is out of range for {} cards", index, len)
+            }
+            CardError::DeckEmpty => write!(f, "Deck is empty"),
+            CardError::DuplicateCard(card) => write!(f, "{} shows up more than once", card),
+            CardError::MissingCard(card) => write!(f, "{} is missing", card),
+            CardError::CardCount { count, min, max } if min == max => {
+                write!(f, "Needs {} cards, got {}", min, count)
+            }
+            CardError::CardCount { count, min, max } => {
+                write!(f, "Needs between {} and {} cards, got {}", min, max, count)
+            }
+            CardError::HoleCardCount { count, min, max } => write!(
+                f,
+                "Needs between {} and {} hole cards, got {}",
+                min, max, count
+            ),
+            CardError::BoardCardCount { count, min, max } => write!(
+                f,
+                "Needs between {} and {} board cards, got {}",
+                min, max, count
+            ),
+            CardError::WildCard(card) => write!(f, "{} can't be used, no wild cards here", card),
+            CardError::DuplicateSuit(suit) => write!(f, "{} is ranked more than once", suit),
+            CardError::JokerCount { count, max } => {
+                write!(f, "A deck holds at most {} jokers, got {}", max, count)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CardError {}
@@ -0,0 +1,457 @@
+use super::Hand;
+use super::card::{PlayingCard, Suit, Value};
+use super::error::CardError;
+use super::lookup;
+use super::low::{LowRank, LowballRule};
+use super::rank::HandRank;
+use super::wild::WildCards;
+
+// CONSTANTS
+const BEST_HAND_SIZE: usize = 5;
+const MAX_CARDS: usize = 7;
+const OMAHA_HOLE_USED: usize = 2;
+const OMAHA_BOARD_USED: usize = 3;
+const OMAHA_MIN_HOLE: usize = 4;
+const OMAHA_MAX_HOLE: usize = 6;
+
+//Finds the best five card hand out of 5, 6 or 7 cards (eg. Hold'em hole cards + board).
+//Every five card combination is ranked with the lookup tables and the strongest one
+//is built into a Hand, so the result holds both the HandType and the five cards.
+pub fn best_hand(cards: &[PlayingCard]) -> Result<Hand, CardError> {
+    if cards.len() < BEST_HAND_SIZE || cards.len() > MAX_CARDS {
+        return Err(CardError::CardCount {
+            count: cards.len(),
+            min: BEST_HAND_SIZE,
+            max: MAX_CARDS,
+        });
+    }
+    no_jokers(cards)?;
+    let mut best: Option<(HandRank, [PlayingCard; BEST_HAND_SIZE])> = None;
+    for combo in combinations(cards.len(), BEST_HAND_SIZE) {
+        let five = [
+            cards[combo[0]],
+            cards[combo[1]],
+            cards[combo[2]],
+            cards[combo[3]],
+            cards[combo[4]],
+        ];
+        let rank = lookup::evaluate(&five);
+        let is_better = match &best {
+            Some((current, _)) => rank > *current,
+            None => true,
+        };
+        if is_better {
+            best = Some((rank, five));
+        }
+    }
+    let (_, five) = best.expect("At least one combination exists for 5+ cards.");
+    Hand::from(five.to_vec())
+}
+
+//Best five card hand out of 5-7 cards with wild cards in play. Each wild card is
+//swapped for whatever card makes the strongest hand, so the Hand returned holds
+//the cards the wilds were played as. Without any wild cards it's just best_hand.
+pub fn best_wild_hand(cards: &[PlayingCard], wild: &WildCards) -> Result<Hand, CardError> {
+    if cards.len() < BEST_HAND_SIZE || cards.len() > MAX_CARDS {
+        return Err(CardError::CardCount {
+            count: cards.len(),
+            min: BEST_HAND_SIZE,
+            max: MAX_CARDS,
+        });
+    }
+    if !cards.iter().any(|c| wild.is_wild(c)) {
+        return best_hand(cards);
+    }
+    let mut best: Option<Hand> = None;
+    for combo in combinations(cards.len(), BEST_HAND_SIZE) {
+        let five: Vec<PlayingCard> = combo.iter().map(|&i| cards[i]).collect();
+        let hand = best_substitution(&five, wild);
+        let is_better = match &best {
+            Some(current) => hand > *current,
+            None => true,
+        };
+        if is_better {
+            best = Some(hand);
+        }
+    }
+    Ok(best.expect("At least one combination exists for 5+ cards."))
+}
+
+// Tries every set of values the wild cards could take. Suits only matter for
+// flushes, so each set is tried once in the natural cards' suit (when they
+// share one) and once off suit, which keeps pairs and sets from being read as a
+// flush.
+fn best_substitution(five: &[PlayingCard], wild: &WildCards) -> Hand {
+    let (naturals, wild_count) = wild.split(five);
+    let natural_suit = naturals.first().map(|c| c.get_suit());
+    let suited = naturals.iter().all(|c| Some(c.get_suit()) == natural_suit);
+    let offset = natural_suit.map_or(0, |s| s.index());
+    let off_suits: Vec<Suit> = (0..wild_count)
+        .map(|i| Suit::ALL[(offset + 1 + i) % Suit::ALL.len()])
+        .collect();
+
+    let mut best: Option<Hand> = None;
+    for values in value_multisets(wild_count) {
+        let mut suit_choices = vec![off_suits.clone()];
+        if suited {
+            suit_choices.push(vec![natural_suit.unwrap_or(Suit::Spade); wild_count]);
+        }
+        for suits in suit_choices {
+            let mut cards = naturals.clone();
+            cards.extend(
+                values
+                    .iter()
+                    .zip(suits)
+                    .map(|(&value, suit)| PlayingCard::new(suit, value)),
+            );
+            let hand = Hand::from_played(cards);
+            let is_better = match &best {
+                Some(current) => hand > *current,
+                None => true,
+            };
+            if is_better {
+                best = Some(hand);
+            }
+        }
+    }
+    best.expect("There's always at least one way to play the wild cards.")
+}
+
+// Every way of giving `count` wild cards a value, ignoring order.
+fn value_multisets(count: usize) -> Vec<Vec<Value>> {
+    let mut sets: Vec<Vec<Value>> = vec![Vec::new()];
+    for _ in 0..count {
+        sets = sets
+            .into_iter()
+            .flat_map(|set| {
+                let start = set.last().map_or(0, |v| v.index());
+                Value::ALL[start..].iter().map(move |&value| {
+                    let mut next = set.clone();
+                    next.push(value);
+                    next
+                })
+            })
+            .collect();
+    }
+    sets
+}
+
+//Best Omaha hand: exactly two of the 4-6 hole cards with exactly three of the
+//3-5 board cards, no matter what else would make a better hand.
+pub fn best_omaha_hand(hole: &[PlayingCard], board: &[PlayingCard]) -> Result<Hand, CardError> {
+    let mut best: Option<(HandRank, [PlayingCard; BEST_HAND_SIZE])> = None;
+    for five in omaha_fives(hole, board)? {
+        let rank = lookup::evaluate(&five);
+        let is_better = match &best {
+            Some((current, _)) => rank > *current,
+            None => true,
+        };
+        if is_better {
+            best = Some((rank, five));
+        }
+    }
+    let (_, five) = best.expect("At least one combination exists for a legal Omaha hand.");
+    Hand::from(five.to_vec())
+}
+
+//Best low under the given rule using two hole cards and three board cards, with
+//the five cards that make it. Ok(None) when there's no qualifying low.
+pub fn best_omaha_low(
+    hole: &[PlayingCard],
+    board: &[PlayingCard],
+    rule: LowballRule,
+) -> Result<Option<(LowRank, Vec<PlayingCard>)>, CardError> {
+    let mut best: Option<(LowRank, Vec<PlayingCard>)> = None;
+    for five in omaha_fives(hole, board)? {
+        if let Some(low) = rule.rank(&five) {
+            let is_better = match &best {
+                Some((current, _)) => low > *current,
+                None => true,
+            };
+            if is_better {
+                best = Some((low, five.to_vec()));
+            }
+        }
+    }
+    Ok(best)
+}
+
+// Every two-from-the-hole plus three-from-the-board combination.
+fn omaha_fives(
+    hole: &[PlayingCard],
+    board: &[PlayingCard],
+) -> Result<Vec<[PlayingCard; BEST_HAND_SIZE]>, CardError> {
+    if hole.len() < OMAHA_MIN_HOLE || hole.len() > OMAHA_MAX_HOLE {
+        return Err(CardError::HoleCardCount {
+            count: hole.len(),
+            min: OMAHA_MIN_HOLE,
+            max: OMAHA_MAX_HOLE,
+        });
+    }
+    if board.len() < OMAHA_BOARD_USED || board.len() > BEST_HAND_SIZE {
+        return Err(CardError::BoardCardCount {
+            count: board.len(),
+            min: OMAHA_BOARD_USED,
+            max: BEST_HAND_SIZE,
+        });
+    }
+    no_jokers(hole)?;
+    no_jokers(board)?;
+    let mut fives = Vec::new();
+    for h in combinations(hole.len(), OMAHA_HOLE_USED) {
+        for b in combinations(board.len(), OMAHA_BOARD_USED) {
+            fives.push([
+                hole[h[0]],
+                hole[h[1]],
+                board[b[0]],
+                board[b[1]],
+                board[b[2]],
+            ]);
+        }
+    }
+    Ok(fives)
+}
+
+// Jokers only have a value once they're played as a wild card (see best_wild_hand),
+// the lookup tables have nothing for them.
+fn no_jokers(cards: &[PlayingCard]) -> Result<(), CardError> {
+    match cards.iter().find(|c| c.is_joker()) {
+        Some(&joker) => Err(CardError::WildCard(joker)),
+        None => Ok(()),
+    }
+}
+
+//Every way of picking k indexes out of 0..n, in lexicographic order.
+pub fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+    for_each_combination(n, k, |combo| result.push(combo.to_vec()));
+    result
+}
+
+//Same as combinations, but hands each one to f instead of building them all up
+//front, for when there are millions (eg. every five card draw from 47 cards).
+pub fn for_each_combination<F: FnMut(&[usize])>(n: usize, k: usize, mut f: F) {
+    if k > n {
+        return;
+    }
+    let mut combo: Vec<usize> = (0..k).collect();
+    loop {
+        f(&combo);
+        // Find the rightmost index that can still move up.
+        let mut i = k;
+        while i > 0 && combo[i - 1] == n - k + i - 1 {
+            i -= 1;
+        }
+        if i == 0 {
+            return;
+        }
+        combo[i - 1] += 1;
+        for j in i..k {
+            combo[j] = combo[j - 1] + 1;
+        }
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hand::card::{Suit, Value};
+    use crate::hand::hand_type::HandType;
+
+    #[test]
+    fn combinations_test() {
+        assert_eq!(combinations(7, 5).len(), 21);
+        assert_eq!(combinations(6, 5).len(), 6);
+        assert_eq!(combinations(5, 5), vec![vec![0, 1, 2, 3, 4]]);
+        assert!(combinations(4, 5).is_empty());
+    }
+
+    #[test]
+    fn best_hand_test() {
+        // Flush on board beats the pair in the hole.
+        let flush = best_hand(&[
+            PlayingCard::new(Suit::Spade, Value::Nine),
+            PlayingCard::new(Suit::Diamond, Value::Nine),
+            PlayingCard::new(Suit::Heart, Value::Two),
+            PlayingCard::new(Suit::Heart, Value::Seven),
+            PlayingCard::new(Suit::Heart, Value::Jack),
+            PlayingCard::new(Suit::Heart, Value::King),
+            PlayingCard::new(Suit::Heart, Value::Four),
+        ])
+        .unwrap();
+        assert_eq!(flush.get_hand_type(), HandType::Flush);
+        assert!(flush.get_hand().iter().all(|c| c.get_suit() == Suit::Heart));
+
+        // Three pairs: keep the top two and the best remaining kicker.
+        let two_pair = best_hand(&[
+            PlayingCard::new(Suit::Spade, Value::Ace),
+            PlayingCard::new(Suit::Diamond, Value::Ace),
+            PlayingCard::new(Suit::Heart, Value::Queen),
+            PlayingCard::new(Suit::Club, Value::Queen),
+            PlayingCard::new(Suit::Heart, Value::Three),
+            PlayingCard::new(Suit::Club, Value::Three),
+            PlayingCard::new(Suit::Heart, Value::Eight),
+        ])
+        .unwrap();
+        assert_eq!(two_pair.get_hand_type(), HandType::TwoPair);
+        let values: Vec<Value> = two_pair.get_hand().iter().map(|c| c.get_value()).collect();
+        assert_eq!(
+            values,
+            vec![
+                Value::Ace,
+                Value::Ace,
+                Value::Queen,
+                Value::Queen,
+                Value::Eight
+            ]
+        );
+
+        // Suited wheel is a straight flush, not a royal flush.
+        let wheel = best_hand(&[
+            PlayingCard::new(Suit::Club, Value::Ace),
+            PlayingCard::new(Suit::Club, Value::Two),
+            PlayingCard::new(Suit::Club, Value::Three),
+            PlayingCard::new(Suit::Club, Value::Four),
+            PlayingCard::new(Suit::Club, Value::Five),
+            PlayingCard::new(Suit::Diamond, Value::King),
+        ])
+        .unwrap();
+        assert_eq!(wheel.get_hand_type(), HandType::StraightFlush);
+
+        assert_eq!(
+            best_hand(&[PlayingCard::new(Suit::Club, Value::Ace)]),
+            Err(CardError::CardCount {
+                count: 1,
+                min: 5,
+                max: 7
+            })
+        );
+
+        let joker = PlayingCard::joker(Suit::Spade);
+        let mut with_joker = cards("Ah Kd Qc Jh 9s 2c");
+        with_joker.push(joker);
+        assert_eq!(best_hand(&with_joker), Err(CardError::WildCard(joker)));
+    }
+
+    fn cards(s: &str) -> Vec<PlayingCard> {
+        s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn omaha_test() {
+        // Four hearts on board but only one in the hole: no flush.
+        let hole = cards("Ah Kc Qd Js");
+        let board = cards("2h 7h 9h Th 3c");
+        let hand = best_omaha_hand(&hole, &board).unwrap();
+        assert_ne!(hand.get_hand_type(), HandType::Flush);
+        assert_eq!(hand.get_hand_type(), HandType::HighCard);
+        // Hold'em rules would find the flush.
+        let mut holdem = vec![hole[0]];
+        holdem.extend(board.iter().copied());
+        assert_eq!(best_hand(&holdem).unwrap().get_hand_type(), HandType::Flush);
+
+        // Quads on the board play as trips at most.
+        let hand = best_omaha_hand(&cards("Ah Kc 5d 6s"), &cards("9h 9c 9d 9s 2c")).unwrap();
+        assert_eq!(hand.get_hand_type(), HandType::ThreeKind);
+
+        // Two in the hole make the straight.
+        let hand = best_omaha_hand(&cards("8c 6d Ks Kd"), &cards("9h 7c 5d Qs 2c")).unwrap();
+        assert_eq!(hand.get_hand_type(), HandType::Straight);
+
+        assert_eq!(
+            best_omaha_hand(&cards("Ah Kc"), &board),
+            Err(CardError::HoleCardCount {
+                count: 2,
+                min: 4,
+                max: 6
+            })
+        );
+        assert_eq!(
+            best_omaha_hand(&hole, &cards("2h 7h")),
+            Err(CardError::BoardCardCount {
+                count: 2,
+                min: 3,
+                max: 5
+            })
+        );
+
+        let joker = PlayingCard::joker(Suit::Heart);
+        let mut hole = cards("Ah Kc Qd");
+        hole.push(joker);
+        assert_eq!(
+            best_omaha_hand(&hole, &board),
+            Err(CardError::WildCard(joker))
+        );
+        assert_eq!(
+            best_omaha_low(&hole, &board, LowballRule::EightOrBetter),
+            Err(CardError::WildCard(joker))
+        );
+    }
+
+    #[test]
+    fn omaha_low_test() {
+        let (low, five) = best_omaha_low(
+            &cards("Ah 2c Kd Ks"),
+            &cards("3h 6c 8d Qs Jc"),
+            LowballRule::EightOrBetter,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(low.to_string(), "8-6-3-2-A");
+        assert_eq!(five.len(), 5);
+        // Only one low card in the hole, so no low.
+        assert_eq!(
+            best_omaha_low(
+                &cards("Ah Kc Qd Ks"),
+                &cards("3h 6c 8d 2s 4c"),
+                LowballRule::EightOrBetter
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn wild_hand_test() {
+        let jokers = WildCards::new();
+        let deuces = WildCards::deuces();
+        let joker = PlayingCard::joker(Suit::Spade);
+
+        // Joker fills the inside straight flush rather than pairing the king.
+        let mut hole = cards("Kh Qh Jh 9h");
+        hole.push(joker);
+        let hand = best_wild_hand(&hole, &jokers).unwrap();
+        assert_eq!(hand.get_hand_type(), HandType::StraightFlush);
+        assert_eq!(
+            hand.rank(),
+            "Kh Qh Jh Th 9h".parse::<Hand>().unwrap().rank()
+        );
+
+        // Four aces and a joker, or aces and deuces, make five of a kind.
+        let mut aces = cards("As Ah Ad Ac");
+        aces.push(joker);
+        let five_aces = best_wild_hand(&aces, &jokers).unwrap();
+        assert_eq!(five_aces.get_hand_type(), HandType::FiveKind);
+        let deuces_aces = best_wild_hand(&cards("As Ah 2d 2c 2s 7h 8d"), &deuces).unwrap();
+        assert_eq!(deuces_aces.rank(), five_aces.rank());
+        let royal: Hand = "As Ks Qs Js Ts".parse().unwrap();
+        assert!(five_aces > royal);
+
+        // Suited naturals can make a flush, but quads beat it.
+        let quads = best_wild_hand(&cards("9s 4s 2d 2c 2h"), &deuces).unwrap();
+        assert_eq!(quads.get_hand_type(), HandType::FourKind);
+
+        // No wild cards in the hand, same as best_hand.
+        let plain = cards("As Kd Qc Jh 9s 3c 4d");
+        assert_eq!(best_wild_hand(&plain, &deuces), best_hand(&plain));
+        assert_eq!(
+            best_wild_hand(&cards("2s 2h"), &deuces),
+            Err(CardError::CardCount {
+                count: 2,
+                min: 5,
+                max: 7
+            })
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+
+use super::Hand;
+use super::card_set::CardSet;
+use super::evaluator::for_each_combination;
+use super::hand_type::HandType;
+use super::lookup;
+use super::rank::HandRank;
+
+// CONSTANTS
+const HAND_SIZE: usize = 5;
+// Every category a hand without wild cards can land in, best first.
+const CATEGORIES: [HandType; 10] = [
+    HandType::RoyalFlush,
+    HandType::StraightFlush,
+    HandType::FourKind,
+    HandType::FullHouse,
+    HandType::Flush,
+    HandType::Straight,
+    HandType::ThreeKind,
+    HandType::TwoPair,
+    HandType::Pair,
+    HandType::HighCard,
+];
+
+///How many five card hands land in one HandType, and how many of them rank
+///differently (eg. 156 kinds of four of a kind, counting the kicker).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryCount {
+    hand_type: HandType,
+    hands: u64,
+    classes: usize,
+}
+
+impl CategoryCount {
+    //Standard getter.
+    pub fn get_hand_type(&self) -> HandType {
+        self.hand_type.clone()
+    }
+    //Standard getter.
+    pub fn get_hands(&self) -> u64 {
+        self.hands
+    }
+    //Standard getter.
+    pub fn get_classes(&self) -> usize {
+        self.classes
+    }
+}
+
+///Result of ranking every five card hand in a 52 card deck.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandFrequencies {
+    categories: Vec<CategoryCount>,
+    total: u64,
+    classes: usize,
+    mismatches: u64,
+    untyped: u64,
+}
+
+impl HandFrequencies {
+    //Every category, best first, including any that came up empty.
+    pub fn get_categories(&self) -> &Vec<CategoryCount> {
+        &self.categories
+    }
+    //Number of hands ranked, C(52,5) = 2,598,960.
+    pub fn get_total(&self) -> u64 {
+        self.total
+    }
+    //Number of distinct ranks across every category, 7,462.
+    pub fn get_classes(&self) -> usize {
+        self.classes
+    }
+    //Hands Hand::rank and the lookup table disagreed on. Should always be 0.
+    pub fn get_mismatches(&self) -> u64 {
+        self.mismatches
+    }
+    //Hands check_hand_type gave a type outside the categories (eg. None), left
+    //out of every category count. Should always be 0.
+    pub fn get_untyped(&self) -> u64 {
+        self.untyped
+    }
+
+    //Hands in the given category, 0 for a type no five card hand can be.
+    pub fn count(&self, hand_type: HandType) -> u64 {
+        self.categories
+            .iter()
+            .find(|c| c.hand_type == hand_type)
+            .map_or(0, |c| c.hands)
+    }
+
+    //Chance a random five card hand lands in the given category, 0 to 1.
+    pub fn probability(&self, hand_type: HandType) -> f64 {
+        self.count(hand_type) as f64 / self.total as f64
+    }
+}
+
+//Deals out every five card hand, types and ranks it with Hand (check_hand_type
+//and the tiebreaks) and checks each rank against the lookup table. A couple of
+//seconds in a release build, a lot longer in debug.
+pub fn five_card_frequencies() -> HandFrequencies {
+    let deck = CardSet::full_deck().to_vec();
+    let mut hands = [0u64; CATEGORIES.len()];
+    let mut ranks: Vec<HashSet<HandRank>> = vec![HashSet::new(); CATEGORIES.len()];
+    let mut total = 0;
+    let mut mismatches = 0;
+    let mut untyped = 0;
+    for_each_combination(deck.len(), HAND_SIZE, |combo| {
+        let cards: Vec<_> = combo.iter().map(|&i| deck[i]).collect();
+        let five = [cards[0], cards[1], cards[2], cards[3], cards[4]];
+        let hand = Hand::from(cards).expect("Combinations never repeat a card.");
+        let rank = hand.rank();
+        if lookup::evaluate(&five) != rank {
+            mismatches += 1;
+        }
+        // A five card hand from a plain deck is always one of the categories.
+        match CATEGORIES.iter().position(|t| *t == hand.get_hand_type()) {
+            Some(index) => {
+                hands[index] += 1;
+                ranks[index].insert(rank);
+            }
+            None => untyped += 1,
+        }
+        total += 1;
+    });
+    let categories: Vec<CategoryCount> = CATEGORIES
+        .iter()
+        .zip(hands.iter().zip(ranks.iter()))
+        .map(|(hand_type, (&hands, ranks))| CategoryCount {
+            hand_type: hand_type.clone(),
+            hands,
+            classes: ranks.len(),
+        })
+        .collect();
+    HandFrequencies {
+        classes: categories.iter().map(|c| c.classes).sum(),
+        categories,
+        total,
+        mismatches,
+        untyped,
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn frequencies_test() {
+        let frequencies = five_card_frequencies();
+        assert_eq!(frequencies.get_total(), 2_598_960);
+        assert_eq!(frequencies.get_classes(), 7_462);
+        assert_eq!(frequencies.get_mismatches(), 0);
+        assert_eq!(frequencies.get_untyped(), 0);
+
+        let expected = [
+            (HandType::RoyalFlush, 4, 1),
+            (HandType::StraightFlush, 36, 9),
+            (HandType::FourKind, 624, 156),
+            (HandType::FullHouse, 3_744, 156),
+            (HandType::Flush, 5_108, 1_277),
+            (HandType::Straight, 10_200, 10),
+            (HandType::ThreeKind, 54_912, 858),
+            (HandType::TwoPair, 123_552, 858),
+            (HandType::Pair, 1_098_240, 2_860),
+            (HandType::HighCard, 1_302_540, 1_277),
+        ];
+        for (category, (hand_type, hands, classes)) in
+            frequencies.get_categories().iter().zip(expected)
+        {
+            assert_eq!(category.get_hand_type(), hand_type);
+            assert_eq!(category.get_hands(), hands);
+            assert_eq!(category.get_classes(), classes);
+        }
+        assert_eq!(frequencies.count(HandType::FiveKind), 0);
+        assert!((frequencies.probability(HandType::FourKind) - 624.0 / 2_598_960.0).abs() < 1e-15);
+    }
+}
@@ -1,5 +1,6 @@
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum HandType {
+    FiveKind,
     RoyalFlush,
     StraightFlush,
     FourKind,
@@ -11,5 +12,4 @@ pub enum HandType {
     Pair,
     HighCard,
     None,
-    Deck,
 }
@@ -0,0 +1,161 @@
+use std::sync::OnceLock;
+
+use super::Hand;
+use super::card::{PlayingCard, Suit, Value};
+use super::rank::HandRank;
+
+// Table based five card evaluator. Ranks are turned into bit patterns and primes
+// so any hand is ranked in a couple of lookups instead of a sort and count:
+//   - Five different ranks: the 13 bit rank mask indexes straight into a table
+//     (one for flushes, one for everything else).
+//   - Any pair or better: the product of the rank primes is unique per set of
+//     ranks, and gets binary searched in a sorted table.
+// Tables are filled once from the regular Hand evaluation, so both always agree.
+
+// CONSTANTS
+const RANK_COUNT: usize = Value::ALL.len();
+const MASK_TABLE_SIZE: usize = 1 << RANK_COUNT;
+const RANK_PRIMES: [u32; RANK_COUNT] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+struct Tables {
+    flushes: Vec<HandRank>,
+    unique: Vec<HandRank>,
+    // (prime product, rank) sorted by product.
+    paired: Vec<(u32, HandRank)>,
+}
+
+static TABLES: OnceLock<Tables> = OnceLock::new();
+
+//Ranks exactly five cards. Same result as Hand::from(cards).rank(), minus the
+//sorting and allocations.
+pub fn evaluate(cards: &[PlayingCard; 5]) -> HandRank {
+    let tables = TABLES.get_or_init(build_tables);
+    let mut mask: usize = 0;
+    let mut product: u32 = 1;
+    for c in cards {
+        let index = c.get_value().index();
+        mask |= 1 << index;
+        product *= RANK_PRIMES[index];
+    }
+    if mask.count_ones() == 5 {
+        let suit = cards[0].get_suit();
+        if cards.iter().all(|c| c.get_suit() == suit) {
+            tables.flushes[mask]
+        } else {
+            tables.unique[mask]
+        }
+    } else {
+        let index = tables
+            .paired
+            .binary_search_by_key(&product, |&(p, _)| p)
+            .expect("Every paired rank combination is in the table.");
+        tables.paired[index].1
+    }
+}
+
+// Builds one sample hand per rank pattern and stores what the Hand evaluation says.
+// Suits cycle across the cards (so no flush, and no repeated card in quads)
+// unless a flush is asked for.
+fn sample_rank(ranks: &[usize], flush: bool) -> HandRank {
+    let cards: Vec<PlayingCard> = ranks
+        .iter()
+        .enumerate()
+        .map(|(i, &r)| {
+            let suit = if flush {
+                Suit::ALL[0]
+            } else {
+                Suit::ALL[i % Suit::ALL.len()]
+            };
+            PlayingCard::new(suit, Value::ALL[r])
+        })
+        .collect();
+    Hand::from(cards)
+        .expect("Suits cycle, so no card shows up twice.")
+        .rank()
+}
+
+fn build_tables() -> Tables {
+    let empty = HandRank::new(0, &[]);
+    let mut flushes = vec![empty; MASK_TABLE_SIZE];
+    let mut unique = vec![empty; MASK_TABLE_SIZE];
+    for mask in 0..MASK_TABLE_SIZE {
+        if mask.count_ones() != 5 {
+            continue;
+        }
+        let ranks: Vec<usize> = (0..RANK_COUNT).filter(|r| mask & (1 << r) != 0).collect();
+        flushes[mask] = sample_rank(&ranks, true);
+        unique[mask] = sample_rank(&ranks, false);
+    }
+
+    let mut paired = Vec::new();
+    for a in 0..RANK_COUNT {
+        for b in a..RANK_COUNT {
+            for c in b..RANK_COUNT {
+                for d in c..RANK_COUNT {
+                    for e in d..RANK_COUNT {
+                        let ranks = [a, b, c, d, e];
+                        let all_different = a < b && b < c && c < d && d < e;
+                        // Five of one rank can't happen in a single deck.
+                        if all_different || a == e {
+                            continue;
+                        }
+                        let product = ranks.iter().map(|&r| RANK_PRIMES[r]).product();
+                        paired.push((product, sample_rank(&ranks, false)));
+                    }
+                }
+            }
+        }
+    }
+    paired.sort_by_key(|&(p, _)| p);
+
+    Tables {
+        flushes,
+        unique,
+        paired,
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn table_size_test() {
+        let tables = TABLES.get_or_init(build_tables);
+        // 13 choose 5 = 1287 rank sets without a pair, 6175 - 1287 with one.
+        assert_eq!(tables.paired.len(), 4888);
+        assert_eq!(
+            tables.unique.iter().filter(|r| r.category() != 0).count(),
+            1287
+        );
+    }
+
+    // Walks every one of the 2,598,960 five card hands.
+    #[test]
+    fn matches_check_hand_type_test() {
+        let mut deck: Vec<PlayingCard> = Vec::new();
+        for &suit in &Suit::ALL {
+            for &value in &Value::ALL {
+                deck.push(PlayingCard::new(suit, value));
+            }
+        }
+        let mut checked = 0;
+        for a in 0..deck.len() {
+            for b in a + 1..deck.len() {
+                for c in b + 1..deck.len() {
+                    for d in c + 1..deck.len() {
+                        for e in d + 1..deck.len() {
+                            let cards = [deck[a], deck[b], deck[c], deck[d], deck[e]];
+                            let hand = Hand::from(cards.to_vec()).unwrap();
+                            let rank = evaluate(&cards);
+                            assert_eq!(rank, hand.rank(), "{}", hand);
+                            assert_eq!(rank.hand_type(), hand.get_hand_type());
+                            checked += 1;
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(checked, 2_598_960);
+    }
+}
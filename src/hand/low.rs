@@ -0,0 +1,298 @@
+use core::fmt;
+
+use super::card::PlayingCard;
+use super::evaluator::combinations;
+
+// CONSTANTS
+const VALUE_BITS: u32 = 4;
+const LOW_SIZE: usize = 5;
+const MAX_CARDS: usize = 7;
+// Highest card a qualifying eight-or-better low may hold.
+const EIGHT_OR_BETTER: u8 = 8;
+
+///Which lowball ranking to use. Razz and A-5 draw use AceToFive, 2-7 triple
+///draw uses DeuceToSeven, the low half of Hi-Lo games uses EightOrBetter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LowballRule {
+    //Aces low, straights and flushes don't count. Pairs do.
+    AceToFive,
+    //Aces high, straights and flushes count against you, so 7-5-4-3-2 is the nuts.
+    DeuceToSeven,
+    //Ace-to-five, but only five different cards eight or lower qualify.
+    #[default]
+    EightOrBetter,
+}
+
+impl LowballRule {
+    //Ranks exactly five cards. None if it isn't five cards, or for eight-or-better
+    //when the hand doesn't qualify.
+    pub fn rank(&self, cards: &[PlayingCard]) -> Option<LowRank> {
+        if cards.len() != LOW_SIZE {
+            return None;
+        }
+        match self {
+            LowballRule::AceToFive => Some(ace_to_five(cards)),
+            LowballRule::DeuceToSeven => Some(deuce_to_seven(cards)),
+            LowballRule::EightOrBetter => {
+                let low = ace_to_five(cards);
+                let qualifies =
+                    low.category() == 0 && low.values().iter().all(|&v| v <= EIGHT_OR_BETTER);
+                Some(low).filter(|_| qualifies)
+            }
+        }
+    }
+
+    //Best low out of 5-7 cards (eg. Razz), with the five cards that make it.
+    pub fn best(&self, cards: &[PlayingCard]) -> Option<(LowRank, Vec<PlayingCard>)> {
+        if cards.len() < LOW_SIZE || cards.len() > MAX_CARDS {
+            return None;
+        }
+        let mut best: Option<(LowRank, Vec<PlayingCard>)> = None;
+        for combo in combinations(cards.len(), LOW_SIZE) {
+            let five: Vec<PlayingCard> = combo.iter().map(|&i| cards[i]).collect();
+            if let Some(low) = self.rank(&five) {
+                let is_better = match &best {
+                    Some((current, _)) => low > *current,
+                    None => true,
+                };
+                if is_better {
+                    best = Some((low, five));
+                }
+            }
+        }
+        best
+    }
+}
+
+///Rank of a five card low hand.
+///Laid out as `category | v1 | v2 | v3 | v4 | v5` like HandRank, where the
+///category counts against the hand (0 is no pair for A-5, high card for 2-7) and
+///the values go biggest group first, then highest first. A smaller number is a
+///better low, so the ordering is flipped: a better low compares as Greater, same
+///direction as HandRank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LowRank(u32);
+
+impl LowRank {
+    //Builds an unpaired rank from five low values (Ace = 1), in any order.
+    pub fn new(values: &[u8]) -> LowRank {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| b.cmp(a));
+        LowRank::with_category(0, &sorted)
+    }
+
+    //Builds a rank from a category and the values in the order they're compared.
+    pub fn with_category(category: u8, values: &[u8]) -> LowRank {
+        let mut rank = category as u32;
+        for slot in 0..LOW_SIZE {
+            let value = values.get(slot).copied().unwrap_or(0);
+            rank = (rank << VALUE_BITS) | value as u32;
+        }
+        LowRank(rank)
+    }
+
+    //Raw packed number, smaller is a better low.
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+
+    //What's counting against the hand, 0 when nothing is.
+    pub fn category(&self) -> u8 {
+        (self.0 >> (VALUE_BITS * LOW_SIZE as u32)) as u8
+    }
+
+    //The five values, highest first (eg. [5, 4, 3, 2, 1] for the wheel).
+    pub fn values(&self) -> Vec<u8> {
+        (0..LOW_SIZE)
+            .rev()
+            .map(|slot| ((self.0 >> (slot as u32 * VALUE_BITS)) & 0xF) as u8)
+            .collect()
+    }
+}
+
+impl PartialOrd for LowRank {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LowRank {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl fmt::Display for LowRank {
+    //Written like "8-5-4-2-A".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<String> = self
+            .values()
+            .iter()
+            .map(|&v| match v {
+                1 | 14 => "A".to_string(),
+                13 => "K".to_string(),
+                12 => "Q".to_string(),
+                11 => "J".to_string(),
+                10 => "T".to_string(),
+                v => v.to_string(),
+            })
+            .collect();
+        write!(f, "{}", names.join("-"))
+    }
+}
+
+//Card value for lows, Aces are one.
+pub fn low_value(card: &PlayingCard) -> u8 {
+    match card.numeric_value() {
+        14 => 1,
+        v => v,
+    }
+}
+
+//Eight-or-better low for exactly five cards: five different values, eight or
+//under. Straights and flushes don't count against a low. None if it doesn't qualify.
+pub fn eight_or_better(cards: &[PlayingCard]) -> Option<LowRank> {
+    LowballRule::EightOrBetter.rank(cards)
+}
+
+// Ace-to-five: aces are one, only pairing counts against the hand.
+fn ace_to_five(cards: &[PlayingCard]) -> LowRank {
+    let values: Vec<u8> = cards.iter().map(low_value).collect();
+    let category = match grouped(&values).as_slice() {
+        [1, 1, 1, 1, 1] => 0,
+        [2, 1, 1, 1] => 1,
+        [2, 2, 1] => 2,
+        [3, 1, 1] => 3,
+        [3, 2] => 4,
+        _ => 5,
+    };
+    LowRank::with_category(category, &group_order(&values))
+}
+
+// Deuce-to-seven: high poker turned upside down, aces always high so A-2-3-4-5
+// is just ace high.
+fn deuce_to_seven(cards: &[PlayingCard]) -> LowRank {
+    let values: Vec<u8> = cards.iter().map(|c| c.numeric_value()).collect();
+    let flush = cards.iter().all(|c| c.get_suit() == cards[0].get_suit());
+    let mut sorted = values.clone();
+    sorted.sort();
+    sorted.dedup();
+    let straight = sorted.len() == LOW_SIZE && sorted[LOW_SIZE - 1] - sorted[0] == 4;
+    let category = match (grouped(&values).as_slice(), straight, flush) {
+        (_, true, true) => 8,
+        ([4, 1], _, _) => 7,
+        ([3, 2], _, _) => 6,
+        (_, false, true) => 5,
+        (_, true, false) => 4,
+        ([3, 1, 1], _, _) => 3,
+        ([2, 2, 1], _, _) => 2,
+        ([2, 1, 1, 1], _, _) => 1,
+        _ => 0,
+    };
+    LowRank::with_category(category, &group_order(&values))
+}
+
+// Sizes of the groups of matching values, biggest first.
+fn grouped(values: &[u8]) -> Vec<usize> {
+    let mut sizes: Vec<usize> = Vec::new();
+    let mut seen: Vec<u8> = Vec::new();
+    for &v in values {
+        if !seen.contains(&v) {
+            seen.push(v);
+            sizes.push(values.iter().filter(|&&w| w == v).count());
+        }
+    }
+    sizes.sort_by(|a, b| b.cmp(a));
+    sizes
+}
+
+// Values sorted biggest group first, then highest value first.
+fn group_order(values: &[u8]) -> Vec<u8> {
+    let mut ordered = values.to_vec();
+    ordered.sort_by_key(|&v| {
+        let count = values.iter().filter(|&&w| w == v).count();
+        std::cmp::Reverse((count, v))
+    });
+    ordered
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn cards(s: &str) -> Vec<PlayingCard> {
+        s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn eight_or_better_test() {
+        let wheel = eight_or_better(&cards("Ah 2c 3d 4s 5h")).unwrap();
+        let six_four = eight_or_better(&cards("6h 4c 3d 2s Ah")).unwrap();
+        let eight_seven = eight_or_better(&cards("8h 7c 3d 2s Ah")).unwrap();
+        let eight_six = eight_or_better(&cards("8h 6c 5d 4s 3h")).unwrap();
+        assert!(wheel > six_four);
+        assert!(six_four > eight_six);
+        assert!(eight_six > eight_seven);
+        assert_eq!(wheel.values(), vec![5, 4, 3, 2, 1]);
+        assert_eq!(eight_seven.to_string(), "8-7-3-2-A");
+
+        // Pairs, a nine, or the wrong number of cards don't qualify.
+        assert_eq!(eight_or_better(&cards("Ah Ac 3d 4s 5h")), None);
+        assert_eq!(eight_or_better(&cards("9h 2c 3d 4s 5h")), None);
+        assert_eq!(eight_or_better(&cards("Ah 2c 3d 4s")), None);
+    }
+
+    #[test]
+    fn ace_to_five_test() {
+        let rule = LowballRule::AceToFive;
+        let wheel = rule.rank(&cards("Ah 2h 3h 4h 5h")).unwrap();
+        let king_low = rule.rank(&cards("Kh Qc Jd 9s 8h")).unwrap();
+        let pair_of_aces = rule.rank(&cards("Ah Ac 2d 3s 4h")).unwrap();
+        let pair_of_twos = rule.rank(&cards("2h 2c Kd Qs Jh")).unwrap();
+        // The suited wheel is still the best low.
+        assert!(wheel > king_low);
+        // Any unpaired hand beats a pair, and aces are the lowest pair.
+        assert!(king_low > pair_of_aces);
+        assert!(pair_of_aces > pair_of_twos);
+        assert_eq!(pair_of_aces.category(), 1);
+        assert_eq!(pair_of_aces.to_string(), "A-A-4-3-2");
+        assert!(rule.rank(&cards("Ah 2c 3d 4s")).is_none());
+    }
+
+    #[test]
+    fn deuce_to_seven_test() {
+        let rule = LowballRule::DeuceToSeven;
+        let number_one = rule.rank(&cards("7h 5c 4d 3s 2h")).unwrap();
+        let seven_six = rule.rank(&cards("7h 6c 4d 3s 2h")).unwrap();
+        let ace_high = rule.rank(&cards("Ah 5c 4d 3s 2h")).unwrap();
+        let straight = rule.rank(&cards("6h 5c 4d 3s 2h")).unwrap();
+        let flush = rule.rank(&cards("7h 5h 4h 3h 2h")).unwrap();
+        let pair = rule.rank(&cards("2h 2c 4d 5s 7h")).unwrap();
+        assert!(number_one > seven_six);
+        // A-2-3-4-5 isn't a straight, just a bad ace high.
+        assert_eq!(ace_high.category(), 0);
+        assert!(seven_six > ace_high);
+        assert!(ace_high > pair);
+        assert!(pair > straight);
+        assert!(straight > flush);
+        assert_eq!(number_one.to_string(), "7-5-4-3-2");
+    }
+
+    #[test]
+    fn best_low_test() {
+        // Razz: best five of seven, pairs avoided.
+        let seven = cards("Kh 2c 2d 5s 7h 3c Ah");
+        let (low, five) = LowballRule::AceToFive.best(&seven).unwrap();
+        assert_eq!(low.to_string(), "7-5-3-2-A");
+        assert_eq!(five.len(), 5);
+        let (low, _) = LowballRule::DeuceToSeven.best(&seven).unwrap();
+        assert_eq!(low.to_string(), "K-7-5-3-2");
+        assert!(
+            LowballRule::EightOrBetter
+                .best(&cards("Kh Qc 2d 2s 9h 9c Ah"))
+                .is_none()
+        );
+        assert!(LowballRule::AceToFive.best(&cards("Kh Qc")).is_none());
+    }
+}
@@ -0,0 +1,77 @@
+use super::hand_type::HandType;
+
+// CONSTANTS
+// Each tiebreak value gets 4 bits (values top out at 14), five of them sit
+// under the category.
+const VALUE_BITS: u32 = 4;
+const TIEBREAK_SLOTS: u32 = 5;
+
+///Total-order rank of a five card hand.
+///Laid out as `category | v1 | v2 | v3 | v4 | v5`, one nibble per tiebreak value,
+///so a bigger number is always a better hand and equal numbers are a true tie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandRank(u32);
+
+impl HandRank {
+    //Builds a rank from a category (see Hand::hand_value) and the tiebreak values
+    //in the order they're compared. Unused slots are left at zero.
+    pub fn new(category: u8, tiebreak_values: &[u8]) -> HandRank {
+        let mut rank = category as u32;
+        for slot in 0..TIEBREAK_SLOTS as usize {
+            let value = tiebreak_values.get(slot).copied().unwrap_or(0);
+            rank = (rank << VALUE_BITS) | value as u32;
+        }
+        HandRank(rank)
+    }
+
+    //Raw packed number.
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+
+    //Category number, 11 for five of a kind (wild cards only) down to 1 for a high card.
+    pub fn category(&self) -> u8 {
+        (self.0 >> (VALUE_BITS * TIEBREAK_SLOTS)) as u8
+    }
+
+    //HandType the category stands for.
+    pub fn hand_type(&self) -> HandType {
+        match self.category() {
+            11 => HandType::FiveKind,
+            10 => HandType::RoyalFlush,
+            9 => HandType::StraightFlush,
+            8 => HandType::FourKind,
+            7 => HandType::FullHouse,
+            6 => HandType::Flush,
+            5 => HandType::Straight,
+            4 => HandType::ThreeKind,
+            3 => HandType::TwoPair,
+            2 => HandType::Pair,
+            1 => HandType::HighCard,
+            _ => HandType::None,
+        }
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn packing_test() {
+        let full_house = HandRank::new(7, &[14, 10]);
+        assert_eq!(full_house.value(), 0x7EA000);
+        assert_eq!(full_house.category(), 7);
+        assert_eq!(full_house.hand_type(), HandType::FullHouse);
+
+        // Category always outweighs the kickers.
+        assert!(HandRank::new(3, &[2, 2, 2]) > HandRank::new(2, &[14, 13, 12, 11]));
+        // Same category falls through to the first differing value.
+        assert!(HandRank::new(2, &[9, 14, 5, 3]) > HandRank::new(2, &[9, 14, 4, 3]));
+        assert_eq!(
+            HandRank::new(1, &[14, 9, 7, 4, 2]),
+            HandRank::new(1, &[14, 9, 7, 4, 2])
+        );
+    }
+}
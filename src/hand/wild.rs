@@ -0,0 +1,64 @@
+use super::card::{PlayingCard, Value};
+
+///Which cards play as wild. Jokers are always wild, on top of that any ranks can
+///be named wild (eg. deuces wild). A wild card can stand in for any card, even
+///one already in the hand, which is how five of a kind gets made.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WildCards {
+    ranks: Vec<Value>,
+}
+
+impl WildCards {
+    //Only jokers are wild.
+    pub fn new() -> WildCards {
+        WildCards { ranks: Vec::new() }
+    }
+
+    //Jokers plus every card of the given ranks.
+    pub fn with_ranks(ranks: Vec<Value>) -> WildCards {
+        WildCards { ranks }
+    }
+
+    //Deuces wild.
+    pub fn deuces() -> WildCards {
+        WildCards::with_ranks(vec![Value::Two])
+    }
+
+    //Just a getter.
+    pub fn get_ranks(&self) -> &Vec<Value> {
+        &self.ranks
+    }
+
+    pub fn is_wild(&self, card: &PlayingCard) -> bool {
+        card.is_joker() || self.ranks.contains(&card.get_value())
+    }
+
+    //Splits cards into the natural ones and how many wild cards there are.
+    pub fn split(&self, cards: &[PlayingCard]) -> (Vec<PlayingCard>, usize) {
+        let naturals: Vec<PlayingCard> =
+            cards.iter().filter(|c| !self.is_wild(c)).copied().collect();
+        let wild = cards.len() - naturals.len();
+        (naturals, wild)
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hand::card::Suit;
+
+    #[test]
+    fn is_wild_test() {
+        let deuce: PlayingCard = "2h".parse().unwrap();
+        let joker = PlayingCard::joker(Suit::Spade);
+        assert!(WildCards::new().is_wild(&joker));
+        assert!(!WildCards::new().is_wild(&deuce));
+        assert!(WildCards::deuces().is_wild(&deuce));
+
+        let cards = vec![deuce, joker, "As".parse().unwrap()];
+        let (naturals, wild) = WildCards::deuces().split(&cards);
+        assert_eq!(naturals, vec!["As".parse().unwrap()]);
+        assert_eq!(wild, 2);
+    }
+}
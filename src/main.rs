@@ -1,63 +1,654 @@
-use crate::hand::*;
-use std::cmp::Ordering;
+use crate::deck::Deck;
+use crate::equity::range::Range;
+use crate::equity::{DEFAULT_SAMPLES, EquityCalculator};
+use crate::game::betting::{Action, Betting, Limit, Stakes};
+use crate::game::draw::advisor::{Opponent, discard_options};
+use crate::game::draw::strategy::{
+    DEFAULT_SIMULATIONS, SimulationStrategy, StandardStrategy, Strategy,
+};
+use crate::game::draw::{DEFAULT_MAX_DISCARD, DrawGame, DrawPhase};
+use crate::game::error::GameError;
+use crate::game::holdem::{HoldemGame, Variant};
+use crate::game::showdown::ShowdownEntry;
+use crate::game::stud::{StudGame, StudPhase};
+use crate::hand::Hand;
+use crate::hand::card::{ParseCardError, PlayingCard};
+use crate::hand::describe::{describe, explain};
+use crate::hand::frequency::five_card_frequencies;
+use crate::hand::wild::WildCards;
+use crate::player::{Controller, Player};
+use crate::table::{Positions, Table};
+use std::env;
 use std::io;
+use std::str::FromStr;
 
+pub mod deck;
+pub mod equity;
+pub mod game;
 pub mod hand;
+pub mod player;
+pub mod table;
+
+// CONSTANTS
+const DEFAULT_STACK: u32 = 100;
+const DEFAULT_BIG_BLIND: u32 = 2;
+const DEFAULT_RAISE_CAP: u32 = 4;
+const DEFAULT_HANDS: u64 = 20;
+const ADVICE_SHOWN: usize = 3;
+// Finishing hands less likely than this are left off the advice.
+const ADVICE_MIN_CHANCE: f64 = 0.01;
+
+// Reads `--<name> <value>` off the command line, eg. `--seed 42` to replay a deal.
+fn arg_value<T: FromStr>(name: &str) -> Option<T> {
+    let args: Vec<String> = env::args().collect();
+    let position = args.iter().position(|a| a == name)?;
+    args.get(position + 1)?.parse().ok()
+}
 
 fn main() {
-    let mut house_hand: Hand = Hand::default();
-    let mut player_hand: Hand = Hand::default();
-    let mut deck = Hand::generate_deck();
+    let seed: u64 = arg_value("--seed").unwrap_or_else(rand::random);
+    let seat_count: usize = arg_value("--players").unwrap_or(2);
+    let humans: usize = arg_value("--humans").unwrap_or(1);
+    let max_discard: usize = arg_value("--max-discard").unwrap_or(DEFAULT_MAX_DISCARD);
+    let stack: u32 = arg_value("--stack").unwrap_or(DEFAULT_STACK);
+    let big_blind: u32 = arg_value("--big-blind").unwrap_or(DEFAULT_BIG_BLIND);
+    if let Some(hands) = arg_value::<String>("--equity") {
+        print_equity(&hands, seed);
+        return;
+    }
+    if env::args().any(|a| a == "--frequencies") {
+        print_frequencies();
+        return;
+    }
+    println!("Deal seed: {} (replay with --seed {})", seed, seed);
+
+    let players: Vec<Player> = (0..seat_count)
+        .map(|i| {
+            if i < humans {
+                Player::with_stack(&format!("Player {}", i + 1), Controller::Human, stack)
+            } else {
+                Player::with_stack(
+                    &format!("Computer {}", i + 1 - humans),
+                    Controller::Computer,
+                    stack,
+                )
+            }
+        })
+        .collect();
+    let game_name: String = arg_value("--game").unwrap_or_default();
+    let mut stakes = match game_name.as_str() {
+        "stud" => Stakes::stud(big_blind / 2, big_blind / 2, big_blind),
+        "omaha" | "omaha8" => Stakes {
+            limit: Limit::PotLimit,
+            ..Stakes::blinds(big_blind / 2, big_blind)
+        },
+        _ => Stakes::blinds(big_blind / 2, big_blind),
+    };
+    match arg_value::<String>("--limit").as_deref() {
+        Some("pot") => stakes.limit = Limit::PotLimit,
+        Some("fixed") => {
+            stakes.limit = Limit::FixedLimit {
+                cap: DEFAULT_RAISE_CAP,
+            }
+        }
+        Some("no") => stakes.limit = Limit::NoLimit,
+        _ => (),
+    }
+    let hands: u64 = arg_value("--hands").unwrap_or(DEFAULT_HANDS);
+    // Wild cards are only played in five card draw.
+    let is_draw = !matches!(game_name.as_str(), "holdem" | "omaha" | "omaha8" | "stud");
+    let (wild, jokers) = match arg_value::<String>("--wild").as_deref() {
+        Some("deuces") => (WildCards::deuces(), 0),
+        Some("joker") if is_draw => (WildCards::new(), 1),
+        Some("jokers") if is_draw => (WildCards::new(), 2),
+        _ => (WildCards::new(), 0),
+    };
+    // Computer seats in five card draw play by standard play unless told to simulate.
+    let mut strategy: Box<dyn Strategy> = match arg_value::<String>("--strategy").as_deref() {
+        Some("simulation") => Box::new(SimulationStrategy::new(DEFAULT_SIMULATIONS, seed)),
+        _ => Box::new(StandardStrategy),
+    };
+
+    let mut table = Table::new(seat_count);
+    for player in players {
+        if let Err(err) = table.join_any(player) {
+            println!("{}", err);
+        }
+    }
+    for hand in 0..hands {
+        let positions = match table.next_hand() {
+            Ok(positions) => positions,
+            Err(err) => {
+                println!("{}", err);
+                break;
+            }
+        };
+        println!(
+            "\nHand {}, button on {}.",
+            table.get_hands_played(),
+            table
+                .get_player(positions.get_button())
+                .map_or("an empty seat".to_string(), |p| p.to_string())
+        );
+        let players = table.players_for(&positions);
+        let deck = Deck::seeded_with_jokers(seed.wrapping_add(hand), jokers)
+            .expect("The --wild options add at most two jokers.");
+        let players = match game_name.as_str() {
+            "holdem" => play_holdem(players, deck, &positions, stakes, Variant::Holdem),
+            "omaha" => play_holdem(
+                players,
+                deck,
+                &positions,
+                stakes,
+                Variant::Omaha { hole_cards: 4 },
+            ),
+            "omaha8" => play_holdem(
+                players,
+                deck,
+                &positions,
+                stakes,
+                Variant::OmahaHiLo { hole_cards: 4 },
+            ),
+            "stud" => play_stud(players, deck, stakes),
+            _ => play_draw(
+                players,
+                deck,
+                &positions,
+                max_discard,
+                stakes,
+                &wild,
+                strategy.as_mut(),
+            ),
+        };
+        table.finish_hand(&positions, &players);
 
-    for _i in 0..5 {
-        deck.deal(&mut player_hand);
+        let human_seated = table
+            .active_seats()
+            .iter()
+            .any(|&seat| table.get_player(seat).is_some_and(|p| p.is_human()));
+        if human_seated && hand + 1 < hands {
+            println!("Play another hand? (y/n)");
+            if read_input().is_none_or(|input| input.to_uppercase() != "Y") {
+                break;
+            }
+        }
     }
+}
 
-    for _i in 0..5 {
-        deck.deal(&mut house_hand);
+// Odds for hands or ranges split by "vs", eg. "As Ks vs QQ+, AKs", with the
+// --board and --dead cards taken out. Sampled boards use the seed.
+fn print_equity(players: &str, seed: u64) {
+    let parse = |cards: &str| -> Result<Vec<PlayingCard>, ParseCardError> {
+        cards.split_whitespace().map(|c| c.parse()).collect()
+    };
+    // Ranges use commas themselves, so players are split on "vs". Without one it's
+    // the older comma separated list of known hands ("As Ks, Qd Qc").
+    let names: Vec<&str> = if players.contains("vs") {
+        players.split("vs").map(str::trim).collect()
+    } else {
+        players.split(',').map(str::trim).collect()
+    };
+    let mut ranges = Vec::new();
+    for &player in &names {
+        // Plain cards ("As Ks") are a known hand, anything else is range notation.
+        let range = match parse(player) {
+            Ok(cards) if !cards.is_empty() => Ok(Range::from_hand(cards)),
+            _ => player.parse::<Range>(),
+        };
+        match range {
+            Ok(range) => ranges.push(range),
+            Err(err) => {
+                println!("{}", err);
+                return;
+            }
+        }
+    }
+    let known = parse(&arg_value::<String>("--board").unwrap_or_default()).and_then(|board| {
+        let dead = parse(&arg_value::<String>("--dead").unwrap_or_default())?;
+        Ok((board, dead))
+    });
+    let (board, dead) = match known {
+        Ok(known) => known,
+        Err(err) => {
+            println!("{}", err);
+            return;
+        }
+    };
+    let mut calc = EquityCalculator::with_ranges(ranges);
+    calc.set_board(board);
+    calc.set_dead(dead);
+    calc.set_samples(DEFAULT_SAMPLES, seed);
+    let equity = match calc.calculate() {
+        Ok(equity) => equity,
+        Err(err) => {
+            println!("{}", err);
+            return;
+        }
+    };
+    if equity.is_exhaustive() {
+        println!("Every board checked ({}).", equity.get_boards());
+    } else {
+        println!(
+            "{} boards sampled (replay with --seed {}).",
+            equity.get_boards(),
+            seed
+        );
+    }
+    for (player, result) in names.iter().zip(equity.get_hands()) {
+        println!(
+            "{}: {:.2}% win, {:.2}% tie, {:.2}% lose, {:.2}% equity",
+            player,
+            result.win_percent(),
+            result.tie_percent(),
+            result.loss_percent(),
+            result.equity()
+        );
+    }
+}
+
+// Ranks every five card hand and prints how often each type comes up.
+fn print_frequencies() {
+    let frequencies = five_card_frequencies();
+    println!(
+        "{:<14} {:>10} {:>10} {:>8}",
+        "Hand", "Count", "Percent", "Classes"
+    );
+    for category in frequencies.get_categories() {
+        println!(
+            "{:<14} {:>10} {:>9.4}% {:>8}",
+            format!("{:?}", category.get_hand_type()),
+            category.get_hands(),
+            frequencies.probability(category.get_hand_type()) * 100.0,
+            category.get_classes()
+        );
+    }
+    println!(
+        "{:<14} {:>10} {:>9.4}% {:>8}",
+        "Total",
+        frequencies.get_total(),
+        100.0,
+        frequencies.get_classes()
+    );
+    if frequencies.get_mismatches() > 0 {
+        println!(
+            "{} hands ranked differently by the lookup table!",
+            frequencies.get_mismatches()
+        );
+    }
+    if frequencies.get_untyped() > 0 {
+        println!(
+            "{} hands didn't get a hand type!",
+            frequencies.get_untyped()
+        );
+    }
+}
+
+// Plays one hand of five card draw. Returns the players with their new stacks.
+fn play_draw(
+    players: Vec<Player>,
+    deck: Deck,
+    positions: &Positions,
+    max_discard: usize,
+    stakes: Stakes,
+    wild: &WildCards,
+    strategy: &mut dyn Strategy,
+) -> Vec<Player> {
+    // The advisor only knows a plain 52 card deck.
+    let advise = deck.remaining() <= 52 && wild.get_ranks().is_empty();
+    let mut game = match DrawGame::new(players.clone(), deck, max_discard, stakes) {
+        Ok(game) => game,
+        Err(err) => {
+            println!("{}", err);
+            return players;
+        }
+    };
+    let started = game
+        .set_wild_cards(wild.clone())
+        .and_then(|_| game.set_button(positions.game_button()))
+        .and_then(|_| game.set_blinds(positions.game_small_blind(), positions.game_big_blind()))
+        .and_then(|_| game.deal());
+    if let Err(err) = started {
+        println!("{}", err);
+        return players;
+    }
+
+    draw_betting_round(&mut game, strategy);
+    for seat in game.seats_in_hand() {
+        if game.get_phase() != DrawPhase::Drawing {
+            break;
+        }
+        if game.get_players()[seat].is_human() {
+            human_draw(&mut game, seat, advise);
+        } else {
+            match game.computer_draw(seat, strategy) {
+                Ok(discarded) => {
+                    println!("{} draws {}.", game.get_players()[seat], discarded.len())
+                }
+                Err(err) => println!("{}", err),
+            }
+        }
+    }
+    draw_betting_round(&mut game, strategy);
+
+    if game.seats_in_hand().len() > 1 {
+        for seat in game.seats_in_hand() {
+            let hand = &game.get_hands()[seat];
+            let played = game.played_hand(seat).unwrap_or_else(|_| hand.clone());
+            println!(
+                "{} has {}({}).",
+                game.get_players()[seat],
+                hand,
+                describe(&played)
+            );
+        }
+        print_deciding_hands(&game.showdown(), |seat| game.played_hand(seat).ok());
+    }
+    let payouts = game.settle();
+    print_results(game.get_players(), payouts);
+    game.get_players().clone()
+}
+
+// Plays one hand of Hold'em or Omaha. Returns the players with their new stacks.
+fn play_holdem(
+    players: Vec<Player>,
+    deck: Deck,
+    positions: &Positions,
+    stakes: Stakes,
+    variant: Variant,
+) -> Vec<Player> {
+    let mut game = match HoldemGame::with_variant(players.clone(), deck, stakes, variant) {
+        Ok(game) => game,
+        Err(err) => {
+            println!("{}", err);
+            return players;
+        }
+    };
+    let started = game
+        .set_button(positions.game_button())
+        .and_then(|_| game.set_blinds(positions.game_small_blind(), positions.game_big_blind()))
+        .and_then(|_| game.deal());
+    if let Err(err) = started {
+        println!("{}", err);
+        return players;
+    }
+
+    let mut board_shown = 0;
+    while let Some(seat) = game.to_act() {
+        let board = game.get_board();
+        if board.get_hand().len() != board_shown {
+            board_shown = board.get_hand().len();
+            println!("Board: {}", board);
+        }
+        let shown = format!("{}| board {}", game.get_hole_cards()[seat], board);
+        let action = choose_action(&game.get_players()[seat], game.get_betting(), seat, &shown);
+        match game.act(seat, action) {
+            Ok(_) => println!("{} {}.", game.get_players()[seat], action),
+            Err(err) => println!("{}", err),
+        }
+    }
+
+    if game.seats_in_hand().len() > 1 {
+        println!("Board: {}", game.get_board());
+        for seat in game.seats_in_hand() {
+            if let Some(best) = game.best_hand(seat) {
+                println!(
+                    "{} shows {}and plays {}({}).",
+                    game.get_players()[seat],
+                    game.get_hole_cards()[seat],
+                    best,
+                    describe(&best)
+                );
+            }
+            if let Some(low) = game.best_low(seat) {
+                println!("{} has low {}.", game.get_players()[seat], low);
+            }
+        }
+        print_deciding_hands(&game.showdown(), |seat| game.best_hand(seat));
+    }
+    let payouts = game.settle();
+    print_results(game.get_players(), payouts);
+    game.get_players().clone()
+}
+
+// Plays one hand of seven card stud. Returns the players with their new stacks.
+fn play_stud(players: Vec<Player>, deck: Deck, stakes: Stakes) -> Vec<Player> {
+    let mut game = match StudGame::new(players.clone(), deck, stakes) {
+        Ok(game) => game,
+        Err(err) => {
+            println!("{}", err);
+            return players;
+        }
+    };
+    if let Err(err) = game.deal() {
+        println!("{}", err);
+        return players;
+    }
+    let mut street = None;
+    while let Some(seat) = game.to_act() {
+        if street != Some(game.get_phase()) {
+            street = Some(game.get_phase());
+            for shown in game.seats_in_hand() {
+                println!(
+                    "{} shows {}",
+                    game.get_players()[shown],
+                    game.get_up_cards()[shown]
+                );
+            }
+            if street == Some(StudPhase::ThirdStreet)
+                && let Some(bring_in) = game.get_bring_in()
+            {
+                println!("{} brings it in.", game.get_players()[bring_in]);
+            }
+        }
+        let shown = format!(
+            "{}| showing {}",
+            game.get_down_cards()[seat],
+            game.get_up_cards()[seat]
+        );
+        let action = choose_action(&game.get_players()[seat], game.get_betting(), seat, &shown);
+        match game.act(seat, action) {
+            Ok(_) => println!("{} {}.", game.get_players()[seat], action),
+            Err(err) => println!("{}", err),
+        }
+    }
+
+    if game.seats_in_hand().len() > 1 {
+        for seat in game.seats_in_hand() {
+            if let Some(best) = game.best_hand(seat) {
+                println!(
+                    "{} plays {}({}).",
+                    game.get_players()[seat],
+                    best,
+                    describe(&best)
+                );
+            }
+        }
+        print_deciding_hands(&game.showdown(), |seat| game.best_hand(seat));
+    }
+    let payouts = game.settle();
+    print_results(game.get_players(), payouts);
+    game.get_players().clone()
+}
+
+// Says what settled the showdown: the winning hand against the next best (or
+// the hand it split with).
+fn print_deciding_hands(groups: &[Vec<ShowdownEntry>], hand: impl Fn(usize) -> Option<Hand>) {
+    let mut seats = groups.iter().flatten().map(|entry| entry.get_seat());
+    if let (Some(first), Some(second)) = (seats.next(), seats.next())
+        && let (Some(first), Some(second)) = (hand(first), hand(second))
+    {
+        println!("{}", explain(&first, &second));
+    }
+}
+
+fn print_results(players: &[Player], payouts: Result<Vec<(usize, u32)>, GameError>) {
+    match payouts {
+        Ok(payouts) => {
+            for (seat, amount) in payouts {
+                println!("{} wins {}", players[seat], amount);
+            }
+        }
+        Err(err) => println!("{}", err),
+    }
+    for player in players {
+        println!("{} has {} chips.", player, player.get_stack());
+    }
+}
+
+// Reads a trimmed line from stdin. None once input runs out.
+fn read_input() -> Option<String> {
+    let mut input = String::new();
+    match io::stdin().read_line(&mut input) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(input.trim().to_string()),
+    }
+}
+
+// Runs betting until the current round is over. Computer seats bet by the strategy.
+fn draw_betting_round(game: &mut DrawGame, strategy: &mut dyn Strategy) {
+    while let Some(seat) = game.to_act() {
+        let action = if game.get_players()[seat].is_human() {
+            let shown = game.get_hands()[seat].to_string();
+            choose_action(&game.get_players()[seat], game.get_betting(), seat, &shown)
+        } else {
+            strategy.action(game, seat)
+        };
+        match game.act(seat, action) {
+            Ok(_) => println!("{} {}.", game.get_players()[seat], action),
+            Err(err) => println!("{}", err),
+        }
+    }
+}
+
+// Asks a human seat for a bet, computer seats check when they can and call otherwise.
+// Running out of input checks or folds.
+fn choose_action(player: &Player, betting: &Betting, seat: usize, shown: &str) -> Action {
+    let to_call = betting.amount_to_call(seat);
+    if !player.is_human() {
+        return if to_call == 0 {
+            Action::Check
+        } else {
+            Action::Call
+        };
+    }
+    loop {
+        println!("{}, your hand is: {}", player, shown);
+        let legal: Vec<String> = betting
+            .legal_actions()
+            .iter()
+            .map(|a| a.to_string())
+            .collect();
+        println!(
+            "Pot {}, {} to call, {} chips left. ({}, allin)",
+            betting.get_pot().total(),
+            to_call,
+            betting.get_stacks()[seat],
+            legal.join(", ")
+        );
+        let Some(input) = read_input() else {
+            return if to_call == 0 {
+                Action::Check
+            } else {
+                Action::Fold
+            };
+        };
+        let words: Vec<String> = input.split_whitespace().map(|w| w.to_lowercase()).collect();
+        let amount = words.get(1).and_then(|w| w.parse::<u32>().ok());
+        match (words.first().map(|w| w.as_str()), amount) {
+            (Some("check"), _) => return Action::Check,
+            (Some("call"), _) => return Action::Call,
+            (Some("fold"), _) => return Action::Fold,
+            (Some("allin"), _) => return Action::AllIn,
+            (Some("bet"), Some(amount)) => return Action::Bet(amount),
+            (Some("raise"), Some(amount)) => return Action::Raise(amount),
+            _ => println!("Didn't understand that."),
+        }
+    }
+}
+
+// Swap prompt for a human seat. Keeps asking until the draw goes through.
+fn human_draw(game: &mut DrawGame, seat: usize, advise: bool) {
+    if advise {
+        print_discard_advice(&game.get_hands()[seat], game.get_max_discard());
     }
     loop {
-        println!("Your hand is: {}", player_hand);
+        println!(
+            "{}, your hand is: {}",
+            game.get_players()[seat],
+            game.get_hands()[seat]
+        );
         println!("Do you want to swap out any cards? (y/n)");
-        let mut input = String::new();
-        io::stdin().read_line(&mut input).unwrap();
-        input = input.trim().to_uppercase();
-        match input.as_str() {
+        let input = read_input().unwrap_or_else(|| "N".to_string());
+        match input.to_uppercase().as_str() {
             "Y" => {
-                println!("Enter the index of the cards you want to remove, seperated with spaces");
-                let mut player_input = String::new();
-                io::stdin().read_line(&mut player_input).expect("idk");
-                println!("{}", player_input);
-                let nums: Vec<usize> = player_input
-                    .trim()
-                    .split(' ')
-                    .map(|x| x.parse::<usize>().unwrap())
-                    .collect();
-                player_hand.group_discard(nums.clone());
-                for _i in 0..nums.len() {
-                    deck.deal(&mut player_hand);
+                println!(
+                    "Enter the index of the cards you want to remove (up to {}), seperated with spaces",
+                    game.get_max_discard()
+                );
+                let player_input = read_input().unwrap_or_default();
+                let nums: Vec<usize> = match player_input
+                    .split_whitespace()
+                    .map(|x| x.parse::<usize>())
+                    .collect()
+                {
+                    Ok(nums) => nums,
+                    Err(_) => {
+                        println!("Indexes have to be whole numbers.");
+                        continue;
+                    }
+                };
+                match game.draw(seat, nums) {
+                    Ok(_) => break,
+                    Err(err) => {
+                        println!("{}", err);
+                        continue;
+                    }
                 }
-                break;
             }
             "N" => {
+                if let Err(err) = game.draw(seat, Vec::new()) {
+                    println!("{}", err);
+                }
                 break;
             }
             _ => continue,
         }
     }
-    println!("You have {}.", player_hand);
-    println!("They have {}.", house_hand);
-    let result = player_hand.cmp(&house_hand);
-    match result {
-        Ordering::Greater => {
-            println!("You win");
-        }
-        Ordering::Equal => {
-            println!("Draw...")
-        }
-        Ordering::Less => {
-            println!("House wins...")
-        }
+}
+
+// Shows the best few legal ways to draw from a hand, with the exact odds.
+fn print_discard_advice(hand: &Hand, max_discard: usize) {
+    let options = match discard_options(hand, Opponent::RandomHand) {
+        Ok(options) => options,
+        Err(_) => return,
+    };
+    println!("Best draws (win rate against a random pat hand):");
+    let legal = options
+        .iter()
+        .filter(|o| o.get_discards().len() <= max_discard);
+    for option in legal.take(ADVICE_SHOWN) {
+        let thrown: Vec<String> = option
+            .get_discards()
+            .iter()
+            .map(|&i| format!("{}:{}", i, hand.get_hand()[i]))
+            .collect();
+        let thrown = if thrown.is_empty() {
+            "stand pat".to_string()
+        } else {
+            format!("discard {}", thrown.join(" "))
+        };
+        let likely: Vec<String> = option
+            .probabilities()
+            .iter()
+            .filter(|(_, chance)| *chance >= ADVICE_MIN_CHANCE)
+            .map(|(hand_type, chance)| format!("{:?} {:.1}%", hand_type, chance * 100.0))
+            .collect();
+        println!(
+            "  {:<24} wins {:.1}%  ({})",
+            thrown,
+            option.get_win_rate() * 100.0,
+            likely.join(", ")
+        );
     }
 }
 
@@ -65,52 +656,72 @@ fn main() {
 mod test {
 
     use crate::hand::Hand;
+    use crate::hand::error::CardError;
 
     use super::*;
 
     #[test]
     fn deck_test() {
-        let mut deck = Hand::generate_deck();
-        assert_eq!(deck.get_hand().len(), 52);
-        // Tests to see if you can add more than 52 cards to a deck
-        // If Err is returned, test passes.
-        if let Err(err) = deck.draw(hand::card::PlayingCard::new(
-            hand::card::Suit::Club,
-            hand::card::Value::Ace,
-        )) {
-            println!("{}", err);
-            assert!(true);
-        } else {
-            assert!(false);
-        }
-        // Checks to see if deck discards successfully when a card is present.
-        // Unsuccessful case is tested in the hand_test test.
-        if let Some(_a) = deck.discard(0) {
-            assert!(true);
-        } else {
-            assert!(false);
-        }
-        assert_eq!(deck.get_hand().len(), 51);
+        let mut deck = Deck::shuffled();
+        assert_eq!(deck.remaining(), 52);
+        let mut dealt = Hand::new(52);
+        while deck.deal_to(&mut dealt).is_ok() {}
+        assert_eq!(dealt.get_hand().len(), 52);
+        // Tests to see if you can deal more than 52 cards from a deck
+        assert_eq!(deck.deal(), Err(CardError::DeckEmpty));
+        assert_eq!(
+            dealt.draw(hand::card::PlayingCard::new(
+                hand::card::Suit::Club,
+                hand::card::Value::Ace,
+            )),
+            Err(CardError::HandFull)
+        );
+        // Checks to see if a hand discards successfully when a card is present.
+        // Unsuccessful case is tested in the hand_function_test test.
+        assert!(dealt.discard(0).is_ok());
+        assert_eq!(dealt.get_hand().len(), 51);
     }
 
     #[test]
     fn hand_function_test() {
         // Test default constructor
+        let mut test_hand = Hand::default();
+        assert!(test_hand.get_hand().is_empty());
 
         // Test constructor
 
         // Test getters
 
         // Test setters
+        let ace: hand::card::PlayingCard = "As".parse().unwrap();
+        assert_eq!(test_hand.draw(ace), Ok(ace));
+        assert_eq!(test_hand.draw(ace), Err(CardError::DuplicateCard(ace)));
 
         // Test successful discard
+        assert_eq!(test_hand.discard(0), Ok(ace));
 
         // Test unsuccessful discard
+        assert_eq!(
+            test_hand.discard(0),
+            Err(CardError::IndexOutOfRange { index: 0, len: 0 })
+        );
+        let mut other: Hand = "As Kd Qc".parse().unwrap();
+        assert_eq!(
+            other.group_discard(vec![0, 3]),
+            Err(CardError::IndexOutOfRange { index: 3, len: 3 })
+        );
+        // Nothing is removed when any index is bad.
+        assert_eq!(other.get_hand().len(), 3);
+        assert_eq!(other.group_discard(vec![2, 0, 2]).unwrap().len(), 2);
+
+        // Test dealing between hands
+        let mut empty = Hand::default();
+        assert_eq!(empty.deal(&mut test_hand), Err(CardError::DeckEmpty));
     }
 
     #[test]
     fn scenario_one() {
-        let mut deck = Hand::generate_deck();
+        let mut deck = Deck::shuffled();
         let hand_1 = Hand::default();
         let mut hand_arr: [Hand; 5] = [
             hand_1.clone(),
@@ -120,9 +731,7 @@ mod test {
             hand_1.clone(),
         ];
         for _i in 0..5 {
-            let _ = hand_arr
-                .iter_mut()
-                .map(|c| c.draw(deck.discard(0).unwrap()));
+            let _ = hand_arr.iter_mut().map(|c| deck.deal_to(c));
         }
         let _ = hand_arr.iter().map(|c| println!("{}", c));
     }
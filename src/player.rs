@@ -0,0 +1,67 @@
+use core::fmt;
+
+///Who makes the decisions for a seat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Controller {
+    Human,
+    Computer,
+}
+
+///Player structure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Player {
+    name: String,
+    controller: Controller,
+    stack: u32,
+}
+
+impl Player {
+    //Player without chips, for games played without betting.
+    pub fn new(name: &str, controller: Controller) -> Player {
+        Player::with_stack(name, controller, 0)
+    }
+    pub fn with_stack(name: &str, controller: Controller, stack: u32) -> Player {
+        Player {
+            name: name.to_string(),
+            controller,
+            stack,
+        }
+    }
+    //Standard getter.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    //Standard getter.
+    pub fn get_controller(&self) -> Controller {
+        self.controller
+    }
+    //Standard getter.
+    pub fn get_stack(&self) -> u32 {
+        self.stack
+    }
+    //Standard setter.
+    pub fn set_stack(&mut self, stack: u32) {
+        self.stack = stack;
+    }
+    pub fn is_human(&self) -> bool {
+        self.controller == Controller::Human
+    }
+    //Computer seats named by seat number, one per stack. Saves every game's tests
+    //building their own table.
+    #[cfg(test)]
+    pub fn computers(stacks: &[u32]) -> Vec<Player> {
+        stacks
+            .iter()
+            .enumerate()
+            .map(|(i, &stack)| {
+                Player::with_stack(&format!("Seat {}", i), Controller::Computer, stack)
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
@@ -0,0 +1,370 @@
+use crate::player::Player;
+use crate::table::error::TableError;
+
+pub mod error;
+
+///A player in a seat. Sitting out keeps the seat but skips the player's hands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seat {
+    player: Player,
+    sitting_out: bool,
+}
+
+impl Seat {
+    //Just a getter.
+    pub fn get_player(&self) -> &Player {
+        &self.player
+    }
+    //Standard getter.
+    pub fn is_sitting_out(&self) -> bool {
+        self.sitting_out
+    }
+}
+
+///Who is in the next hand and who posts what. Everything is in table seat
+///numbers, the game_* methods turn them into indexes for a game dealt to
+///just the seats in the hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Positions {
+    seats: Vec<usize>,
+    button: usize,
+    small_blind: Option<usize>,
+    big_blind: usize,
+}
+
+impl Positions {
+    //Seats dealt in, lowest seat first.
+    pub fn get_seats(&self) -> &Vec<usize> {
+        &self.seats
+    }
+    //Standard getter.
+    pub fn get_button(&self) -> usize {
+        self.button
+    }
+    //None when the small blind is dead.
+    pub fn get_small_blind(&self) -> Option<usize> {
+        self.small_blind
+    }
+    //Standard getter.
+    pub fn get_big_blind(&self) -> usize {
+        self.big_blind
+    }
+    //Button sits in front of an empty or sitting out seat.
+    pub fn is_dead_button(&self) -> bool {
+        !self.seats.contains(&self.button)
+    }
+
+    //Index of a table seat among the seats dealt in.
+    pub fn game_index(&self, seat: usize) -> Option<usize> {
+        self.seats.iter().position(|&s| s == seat)
+    }
+    //Button for the game. A dead button acts like it's on the last player before
+    //the blinds, so dealing and action still start in the right place.
+    pub fn game_button(&self) -> usize {
+        match self.game_index(self.button) {
+            Some(index) => index,
+            None => {
+                let first_blind = self.small_blind.unwrap_or(self.big_blind);
+                let index = self.game_index(first_blind).unwrap_or(0);
+                (index + self.seats.len() - 1) % self.seats.len()
+            }
+        }
+    }
+    pub fn game_small_blind(&self) -> Option<usize> {
+        self.small_blind.and_then(|seat| self.game_index(seat))
+    }
+    pub fn game_big_blind(&self) -> usize {
+        self.game_index(self.big_blind).unwrap_or(0)
+    }
+}
+
+///Fixed number of seats that players join, leave and sit out of, and that
+///moves the button from hand to hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    seats: Vec<Option<Seat>>,
+    button: Option<usize>,
+    small_blind_position: Option<usize>,
+    big_blind: Option<usize>,
+    hands_played: u32,
+}
+
+impl Table {
+    pub fn new(seat_count: usize) -> Table {
+        Table {
+            seats: vec![None; seat_count],
+            button: None,
+            small_blind_position: None,
+            big_blind: None,
+            hands_played: 0,
+        }
+    }
+
+    // -------------------------
+    // Getters
+    // -------------------------
+
+    //Just a getter.
+    pub fn get_seats(&self) -> &Vec<Option<Seat>> {
+        &self.seats
+    }
+    pub fn get_player(&self, seat: usize) -> Option<&Player> {
+        self.seats.get(seat)?.as_ref().map(|s| &s.player)
+    }
+    //Where the button was last hand, None before the first hand.
+    pub fn get_button(&self) -> Option<usize> {
+        self.button
+    }
+    //Standard getter.
+    pub fn get_hands_played(&self) -> u32 {
+        self.hands_played
+    }
+    //Seats that will be dealt the next hand.
+    pub fn active_seats(&self) -> Vec<usize> {
+        (0..self.seats.len())
+            .filter(|&seat| matches!(&self.seats[seat], Some(s) if !s.sitting_out))
+            .collect()
+    }
+
+    // -------------------------
+    // Seating
+    // -------------------------
+
+    pub fn join(&mut self, seat: usize, player: Player) -> Result<(), TableError> {
+        match self.seats.get(seat) {
+            None => Err(TableError::SeatOutOfRange(seat)),
+            Some(Some(_)) => Err(TableError::SeatTaken(seat)),
+            Some(None) => {
+                self.seats[seat] = Some(Seat {
+                    sitting_out: player.get_stack() == 0,
+                    player,
+                });
+                Ok(())
+            }
+        }
+    }
+
+    //Takes the lowest open seat. Returns the seat number.
+    pub fn join_any(&mut self, player: Player) -> Result<usize, TableError> {
+        let seat = self
+            .seats
+            .iter()
+            .position(|s| s.is_none())
+            .ok_or(TableError::TableFull)?;
+        self.join(seat, player)?;
+        Ok(seat)
+    }
+
+    pub fn leave(&mut self, seat: usize) -> Result<Player, TableError> {
+        match self.seats.get_mut(seat) {
+            None => Err(TableError::SeatOutOfRange(seat)),
+            Some(slot) => slot
+                .take()
+                .map(|s| s.player)
+                .ok_or(TableError::SeatEmpty(seat)),
+        }
+    }
+
+    pub fn sit_out(&mut self, seat: usize) -> Result<(), TableError> {
+        self.seat_mut(seat)?.sitting_out = true;
+        Ok(())
+    }
+
+    //Back in from the next hand. Needs chips to play.
+    pub fn sit_in(&mut self, seat: usize) -> Result<(), TableError> {
+        let s = self.seat_mut(seat)?;
+        if s.player.get_stack() == 0 {
+            return Err(TableError::NoChips(seat));
+        }
+        s.sitting_out = false;
+        Ok(())
+    }
+
+    // -------------------------
+    // Hands
+    // -------------------------
+
+    //Moves the button on and works out the blinds for the next hand.
+    //The big blind always moves to the next player in, so nobody skips it. The small
+    //blind goes to last hand's big blind seat and the button to last hand's small
+    //blind seat, even if those seats are now empty (dead small blind, dead button).
+    //Heads up the button posts the small blind and the other player the big.
+    pub fn next_hand(&mut self) -> Result<Positions, TableError> {
+        let active = self.active_seats();
+        if active.len() < 2 {
+            return Err(TableError::NotEnoughPlayers {
+                active: active.len(),
+            });
+        }
+        let (button, small_blind, big_blind) = match self.big_blind {
+            None if active.len() == 2 => (active[0], Some(active[0]), active[1]),
+            None => (active[0], Some(active[1]), active[2]),
+            Some(last_big) => {
+                let big = self.next_active(last_big, &active);
+                if active.len() == 2 {
+                    let button = *active.iter().find(|&&s| s != big).unwrap_or(&big);
+                    (button, Some(button), big)
+                } else {
+                    let small = Some(last_big).filter(|seat| active.contains(seat));
+                    let button = self.small_blind_position.unwrap_or(last_big);
+                    (button, small, big)
+                }
+            }
+        };
+        self.button = Some(button);
+        self.small_blind_position = Some(small_blind.unwrap_or(self.big_blind.unwrap_or(button)));
+        self.big_blind = Some(big_blind);
+        self.hands_played += 1;
+        Ok(Positions {
+            seats: active,
+            button,
+            small_blind,
+            big_blind,
+        })
+    }
+
+    //Players dealt into a hand, in game seat order.
+    pub fn players_for(&self, positions: &Positions) -> Vec<Player> {
+        positions
+            .seats
+            .iter()
+            .filter_map(|&seat| self.get_player(seat).cloned())
+            .collect()
+    }
+
+    //Takes the players back from a finished game (game seat order) so stacks carry
+    //over. Anyone who went broke sits out.
+    pub fn finish_hand(&mut self, positions: &Positions, players: &[Player]) {
+        for (&seat, player) in positions.seats.iter().zip(players) {
+            if let Some(Some(s)) = self.seats.get_mut(seat) {
+                s.player = player.clone();
+                if player.get_stack() == 0 {
+                    s.sitting_out = true;
+                }
+            }
+        }
+    }
+
+    fn seat_mut(&mut self, seat: usize) -> Result<&mut Seat, TableError> {
+        match self.seats.get_mut(seat) {
+            None => Err(TableError::SeatOutOfRange(seat)),
+            Some(slot) => slot.as_mut().ok_or(TableError::SeatEmpty(seat)),
+        }
+    }
+
+    // First active seat after `seat`, going round the table.
+    fn next_active(&self, seat: usize, active: &[usize]) -> usize {
+        let count = self.seats.len();
+        (1..=count)
+            .map(|offset| (seat + offset) % count)
+            .find(|s| active.contains(s))
+            .unwrap_or(active[0])
+    }
+}
+
+//PRIVATE TESTS ONLY!! ALL PUBLIC TESTING SHOULD BE DONE IN MAIN!
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::player::Controller;
+
+    fn table(seats: usize, players: &[usize]) -> Table {
+        let mut table = Table::new(seats);
+        for &seat in players {
+            let player = Player::with_stack(&format!("Seat {}", seat), Controller::Computer, 100);
+            table.join(seat, player).unwrap();
+        }
+        table
+    }
+
+    fn blinds(positions: &Positions) -> (usize, Option<usize>, usize) {
+        (
+            positions.get_button(),
+            positions.get_small_blind(),
+            positions.get_big_blind(),
+        )
+    }
+
+    #[test]
+    fn seating_test() {
+        let mut table = table(3, &[1]);
+        let player = Player::with_stack("New", Controller::Human, 50);
+        assert_eq!(table.join(1, player.clone()), Err(TableError::SeatTaken(1)));
+        assert_eq!(
+            table.join(3, player.clone()),
+            Err(TableError::SeatOutOfRange(3))
+        );
+        assert_eq!(table.join_any(player.clone()), Ok(0));
+        assert_eq!(table.join_any(player.clone()), Ok(2));
+        assert_eq!(table.join_any(player), Err(TableError::TableFull));
+
+        table.sit_out(0).unwrap();
+        assert_eq!(table.active_seats(), vec![1, 2]);
+        assert_eq!(table.leave(2).unwrap().get_name(), "New");
+        assert_eq!(table.leave(2), Err(TableError::SeatEmpty(2)));
+        assert_eq!(
+            table.next_hand(),
+            Err(TableError::NotEnoughPlayers { active: 1 })
+        );
+        table.sit_in(0).unwrap();
+        assert_eq!(table.next_hand().unwrap().get_seats(), &vec![0, 1]);
+    }
+
+    #[test]
+    fn button_rotation_test() {
+        let mut table = table(5, &[0, 2, 3]);
+        assert_eq!(blinds(&table.next_hand().unwrap()), (0, Some(2), 3));
+        assert_eq!(blinds(&table.next_hand().unwrap()), (2, Some(3), 0));
+        assert_eq!(blinds(&table.next_hand().unwrap()), (3, Some(0), 2));
+        let positions = table.next_hand().unwrap();
+        assert_eq!(blinds(&positions), (0, Some(2), 3));
+        assert!(!positions.is_dead_button());
+        assert_eq!(positions.game_button(), 0);
+        assert_eq!(positions.game_small_blind(), Some(1));
+        assert_eq!(positions.game_big_blind(), 2);
+        assert_eq!(table.get_hands_played(), 4);
+    }
+
+    #[test]
+    fn dead_button_test() {
+        let mut table = table(5, &[0, 1, 2, 3]);
+        assert_eq!(blinds(&table.next_hand().unwrap()), (0, Some(1), 2));
+        // Last hand's big blind leaves: the small blind is dead, the button still moves.
+        table.leave(2).unwrap();
+        let positions = table.next_hand().unwrap();
+        assert_eq!(blinds(&positions), (1, None, 3));
+        assert_eq!(positions.game_button(), 1);
+        assert_eq!(positions.game_big_blind(), 2);
+
+        // Button goes to the empty seat.
+        let positions = table.next_hand().unwrap();
+        assert_eq!(blinds(&positions), (2, Some(3), 0));
+        assert!(positions.is_dead_button());
+        assert_eq!(positions.get_seats(), &vec![0, 1, 3]);
+        assert_eq!(positions.game_button(), 1);
+        assert_eq!(positions.game_small_blind(), Some(2));
+        assert_eq!(positions.game_big_blind(), 0);
+
+        assert_eq!(blinds(&table.next_hand().unwrap()), (3, Some(0), 1));
+    }
+
+    #[test]
+    fn heads_up_test() {
+        let mut table = table(4, &[0, 1, 3]);
+        assert_eq!(blinds(&table.next_hand().unwrap()), (0, Some(1), 3));
+        table.sit_out(0).unwrap();
+        // Heads up the button is the small blind.
+        assert_eq!(blinds(&table.next_hand().unwrap()), (3, Some(3), 1));
+        assert_eq!(blinds(&table.next_hand().unwrap()), (1, Some(1), 3));
+
+        // Broke players sit out after the hand.
+        let positions = table.next_hand().unwrap();
+        let mut players = table.players_for(&positions);
+        players[0].set_stack(0);
+        players[1].set_stack(200);
+        table.finish_hand(&positions, &players);
+        assert!(table.get_seats()[1].as_ref().unwrap().is_sitting_out());
+        assert_eq!(table.get_player(3).unwrap().get_stack(), 200);
+        assert_eq!(table.sit_in(1), Err(TableError::NoChips(1)));
+    }
+}
@@ -0,0 +1,33 @@
+use core::fmt;
+
+///Reasons the table can refuse a seat change or a new hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableError {
+    SeatOutOfRange(usize),
+    SeatTaken(usize),
+    SeatEmpty(usize),
+    TableFull,
+    NoChips(usize),
+    NotEnoughPlayers { active: usize },
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableError::SeatOutOfRange(seat) => write!(f, "There is no seat {}", seat),
+            TableError::SeatTaken(seat) => write!(f, "Seat {} is taken", seat),
+            TableError::SeatEmpty(seat) => write!(f, "Nobody is sitting in seat {}", seat),
+            TableError::TableFull => write!(f, "Every seat is taken"),
+            TableError::NoChips(seat) => write!(f, "Seat {} has no chips to play with", seat),
+            TableError::NotEnoughPlayers { active } => {
+                write!(
+                    f,
+                    "Need at least 2 players in to deal, {} sitting in",
+                    active
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TableError {}